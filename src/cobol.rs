@@ -0,0 +1,283 @@
+//! COBOL packed-decimal (`COMP-3`) and zoned-decimal fields.
+//!
+//! A field is described by its `PIC` clause: `PIC S9(5)V99` is seven digits,
+//! two of them after the implied decimal point, with a sign. Conversions
+//! between the field's scale and `SCALE` are exact; digits that would be
+//! lost give `TooManyFractionalDigits`, and values with more digits than
+//! the field give `Overflow`.
+//!
+//! Malformed input is reported as `MalformedDigit` with the offset of the
+//! offending byte.
+
+use crate::{Decimal, DecimalError, DecimalInt};
+
+/// Most digits a field may have; `10^38` still fits a `u128`.
+const MAX_DIGITS: u32 = 38;
+
+/// Sign nibbles written by encoders.
+const PLUS: u8 = 0xC;
+const MINUS: u8 = 0xD;
+const UNSIGNED: u8 = 0xF;
+
+/// A numeric `PIC` definition: total digits, implied decimals and sign.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PicField {
+    digits: u32,
+    scale: u32,
+    signed: bool,
+}
+
+/// Character set of a zoned-decimal field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ZonedCharset {
+    /// Zone nibble `F` on each digit, sign in the zone of the last byte.
+    Ebcdic,
+    /// ASCII digits, sign overpunched on the last byte as `{`, `A`–`I`
+    /// (positive) or `}`, `J`–`R` (negative).
+    Ascii,
+}
+
+impl PicField {
+    /// Returns `Invalid` unless `1 <= digits <= 38` and `scale <= digits`.
+    pub fn new(digits: u32, scale: u32, signed: bool) -> Result<Self, DecimalError> {
+        if digits == 0 || digits > MAX_DIGITS || scale > digits {
+            return Err(DecimalError::Invalid);
+        }
+        Ok(PicField {
+            digits,
+            scale,
+            signed,
+        })
+    }
+
+    pub fn digits(self) -> u32 {
+        self.digits
+    }
+
+    pub fn scale(self) -> u32 {
+        self.scale
+    }
+
+    pub fn signed(self) -> bool {
+        self.signed
+    }
+
+    /// Bytes of a packed field: a nibble per digit plus the sign nibble.
+    pub fn packed_len(self) -> usize {
+        self.digits as usize / 2 + 1
+    }
+
+    /// Bytes of a zoned field: one per digit.
+    pub fn zoned_len(self) -> usize {
+        self.digits as usize
+    }
+
+    /// Encode as packed decimal. Signed fields end in `C` or `D`, unsigned
+    /// ones in `F`; an even digit count gets a leading zero nibble.
+    pub fn encode_packed<T: DecimalInt, const SCALE: u32>(
+        self,
+        value: Decimal<T, SCALE>,
+    ) -> Result<Vec<u8>, DecimalError> {
+        let (magnitude, negative) = self.to_field(value)?;
+        let sign = match (self.signed, negative) {
+            (false, _) => UNSIGNED,
+            (true, false) => PLUS,
+            (true, true) => MINUS,
+        };
+
+        let len = self.packed_len();
+        let digits = format!("{magnitude:0width$}", width = 2 * len - 1);
+        let mut nibbles = digits.bytes().map(|digit| digit - b'0').chain([sign]);
+        Ok((0..len)
+            .map(|_| {
+                let high = nibbles.next().unwrap_or(0);
+                let low = nibbles.next().unwrap_or(0);
+                (high << 4) | low
+            })
+            .collect())
+    }
+
+    /// Decode a packed-decimal field of exactly `packed_len` bytes.
+    ///
+    /// Signed fields accept the sign nibbles `A`, `C`, `E`, `F` (positive)
+    /// and `B`, `D` (negative); unsigned fields accept `C` and `F`. A digit
+    /// nibble above 9, a bad sign or a non-zero pad nibble is
+    /// `MalformedDigit`. Returns `Truncated` for short input and `Invalid`
+    /// for trailing bytes.
+    pub fn decode_packed<T: DecimalInt, const SCALE: u32>(
+        self,
+        bytes: &[u8],
+    ) -> Result<Decimal<T, SCALE>, DecimalError> {
+        let bytes = exact(bytes, self.packed_len())?;
+        let last = bytes.len() - 1;
+
+        let mut magnitude = 0_u128;
+        for (offset, &byte) in bytes.iter().enumerate() {
+            let (high, low) = (byte >> 4, byte & 0x0f);
+            // An even digit count leaves an unused high nibble in front.
+            let pad = offset == 0 && self.digits.is_multiple_of(2);
+            if high > 9 || (pad && high != 0) || (offset < last && low > 9) {
+                return Err(DecimalError::MalformedDigit { offset });
+            }
+            magnitude = magnitude * 10 + u128::from(high);
+            if offset < last {
+                magnitude = magnitude * 10 + u128::from(low);
+            }
+        }
+
+        let negative = match (self.signed, bytes[last] & 0x0f) {
+            (true, 0xA | 0xC | 0xE | 0xF) | (false, PLUS | UNSIGNED) => false,
+            (true, 0xB | MINUS) => true,
+            _ => return Err(DecimalError::MalformedDigit { offset: last }),
+        };
+        self.to_decimal(magnitude, negative)
+    }
+
+    /// Encode as zoned decimal, one byte per digit with the sign carried by
+    /// the last byte. Unsigned fields carry no sign.
+    pub fn encode_zoned<T: DecimalInt, const SCALE: u32>(
+        self,
+        value: Decimal<T, SCALE>,
+        charset: ZonedCharset,
+    ) -> Result<Vec<u8>, DecimalError> {
+        let (magnitude, negative) = self.to_field(value)?;
+        let mut bytes = format!("{magnitude:0width$}", width = self.zoned_len()).into_bytes();
+        let last = bytes.len() - 1;
+        let digit = bytes[last] - b'0';
+
+        match charset {
+            ZonedCharset::Ebcdic => {
+                for byte in bytes.iter_mut() {
+                    *byte = (UNSIGNED << 4) | (*byte - b'0');
+                }
+                if self.signed {
+                    let zone = if negative { MINUS } else { PLUS };
+                    bytes[last] = (zone << 4) | digit;
+                }
+            }
+            ZonedCharset::Ascii if self.signed => {
+                bytes[last] = match (negative, digit) {
+                    (false, 0) => b'{',
+                    (true, 0) => b'}',
+                    (false, digit) => b'A' + digit - 1,
+                    (true, digit) => b'J' + digit - 1,
+                };
+            }
+            ZonedCharset::Ascii => {}
+        }
+        Ok(bytes)
+    }
+
+    /// Decode a zoned-decimal field of exactly `zoned_len` bytes.
+    ///
+    /// In EBCDIC every digit byte has zone `F`; the last byte of a signed
+    /// field may instead have zone `C` or `D`. In ASCII the last byte of a
+    /// signed field may be an overpunch or a plain digit. Anything else is
+    /// `MalformedDigit`. Returns `Truncated` for short input and `Invalid`
+    /// for trailing bytes.
+    pub fn decode_zoned<T: DecimalInt, const SCALE: u32>(
+        self,
+        bytes: &[u8],
+        charset: ZonedCharset,
+    ) -> Result<Decimal<T, SCALE>, DecimalError> {
+        let bytes = exact(bytes, self.zoned_len())?;
+        let last = bytes.len() - 1;
+
+        let mut magnitude = 0_u128;
+        let mut negative = false;
+        for (offset, &byte) in bytes.iter().enumerate() {
+            let signed_byte = self.signed && offset == last;
+            let digit = match charset {
+                ZonedCharset::Ebcdic => {
+                    let (zone, digit) = (byte >> 4, byte & 0x0f);
+                    match zone {
+                        UNSIGNED if digit <= 9 => Some(digit),
+                        PLUS | MINUS if signed_byte && digit <= 9 => {
+                            negative = zone == MINUS;
+                            Some(digit)
+                        }
+                        _ => None,
+                    }
+                }
+                ZonedCharset::Ascii => match byte {
+                    b'0'..=b'9' => Some(byte - b'0'),
+                    b'{' | b'}' if signed_byte => {
+                        negative = byte == b'}';
+                        Some(0)
+                    }
+                    b'A'..=b'I' if signed_byte => Some(byte - b'A' + 1),
+                    b'J'..=b'R' if signed_byte => {
+                        negative = true;
+                        Some(byte - b'J' + 1)
+                    }
+                    _ => None,
+                },
+            };
+            let digit = digit.ok_or(DecimalError::MalformedDigit { offset })?;
+            magnitude = magnitude * 10 + u128::from(digit);
+        }
+        self.to_decimal(magnitude, negative)
+    }
+
+    /// Magnitude in field units (`10^-scale`) and sign of `value`.
+    fn to_field<T: DecimalInt, const SCALE: u32>(
+        self,
+        value: Decimal<T, SCALE>,
+    ) -> Result<(u128, bool), DecimalError> {
+        let minor = value.minor_units().to_i128();
+        let magnitude = rescale(minor.unsigned_abs(), SCALE, self.scale)?;
+        let negative = minor < 0;
+        if magnitude >= 10_u128.pow(self.digits) || (negative && !self.signed) {
+            return Err(DecimalError::Overflow);
+        }
+        Ok((magnitude, negative))
+    }
+
+    /// Decimal from a magnitude in field units and its sign.
+    fn to_decimal<T: DecimalInt, const SCALE: u32>(
+        self,
+        magnitude: u128,
+        negative: bool,
+    ) -> Result<Decimal<T, SCALE>, DecimalError> {
+        let magnitude = rescale(magnitude, self.scale, SCALE)?;
+        let minor = if negative {
+            0_i128.checked_sub_unsigned(magnitude)
+        } else {
+            i128::try_from(magnitude).ok()
+        };
+        Decimal::from_i128(minor.ok_or(DecimalError::Overflow)?)
+    }
+}
+
+fn exact(bytes: &[u8], len: usize) -> Result<&[u8], DecimalError> {
+    if bytes.len() < len {
+        return Err(DecimalError::Truncated);
+    }
+    if bytes.len() > len {
+        return Err(DecimalError::Invalid);
+    }
+    Ok(bytes)
+}
+
+/// Move a magnitude from scale `from` to scale `to` without losing digits.
+fn rescale(magnitude: u128, from: u32, to: u32) -> Result<u128, DecimalError> {
+    if to >= from {
+        return magnitude
+            .checked_mul(10_u128.pow(to - from))
+            .ok_or(DecimalError::Overflow);
+    }
+    let factor = 10_u128.pow(from - to);
+    if !magnitude.is_multiple_of(factor) {
+        let mut provided = from;
+        let mut rest = magnitude;
+        while rest.is_multiple_of(10) {
+            rest /= 10;
+            provided -= 1;
+        }
+        return Err(DecimalError::TooManyFractionalDigits {
+            provided: provided as usize,
+            allowed: to as usize,
+        });
+    }
+    Ok(magnitude / factor)
+}
@@ -0,0 +1,251 @@
+//! Streaming readers for a column of decimals in CSV or fixed-width text.
+//!
+//! A `ColumnReader` walks a byte buffer line by line, slices one field out
+//! of each line and parses it in place, so no field is copied. Each row
+//! yields its value or a `ColumnError` with the line and byte column, and
+//! the reader keeps `ColumnStats` over the rows it has read.
+
+use std::fmt;
+
+use crate::{Decimal, DecimalAccumulator, DecimalError, DecimalInt, ParseOptions};
+
+/// Where the column sits in each line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColumnLayout {
+    /// Field `index` (from zero) of lines split on `delimiter`. A field in
+    /// double quotes may contain the delimiter; the quotes are not parsed.
+    Delimited { delimiter: u8, index: usize },
+    /// Bytes `start..start + width` of each line; a shorter line gives the
+    /// bytes it has.
+    FixedWidth { start: usize, width: usize },
+}
+
+/// A row that failed to parse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnError {
+    /// Line number, from one.
+    pub line: usize,
+    /// Byte column where the field starts, from one.
+    pub column: usize,
+    /// `Truncated` if the line has no such field, else the parse error.
+    pub error: DecimalError,
+}
+
+impl fmt::Display for ColumnError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.error
+        )
+    }
+}
+
+impl std::error::Error for ColumnError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Counts, total and range of the rows read so far.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColumnStats<T, const SCALE: u32> {
+    rows: usize,
+    errors: usize,
+    values: DecimalAccumulator<T, SCALE>,
+    min: Option<Decimal<T, SCALE>>,
+    max: Option<Decimal<T, SCALE>>,
+}
+
+impl<T: DecimalInt, const SCALE: u32> ColumnStats<T, SCALE> {
+    fn new() -> Self {
+        ColumnStats {
+            rows: 0,
+            errors: 0,
+            values: DecimalAccumulator::new(),
+            min: None,
+            max: None,
+        }
+    }
+
+    fn record(&mut self, row: &Result<Decimal<T, SCALE>, ColumnError>) {
+        self.rows += 1;
+        match *row {
+            Ok(value) => {
+                self.values.add(value);
+                self.min = Some(self.min.map_or(value, |min| min.min(value)));
+                self.max = Some(self.max.map_or(value, |max| max.max(value)));
+            }
+            Err(_) => self.errors += 1,
+        }
+    }
+
+    /// Rows read, parsed or not.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Rows that failed to parse.
+    pub fn errors(&self) -> usize {
+        self.errors
+    }
+
+    /// Count, sum and mean of the parsed values.
+    pub fn values(&self) -> &DecimalAccumulator<T, SCALE> {
+        &self.values
+    }
+
+    /// Smallest parsed value, if any.
+    pub fn min(&self) -> Option<Decimal<T, SCALE>> {
+        self.min
+    }
+
+    /// Largest parsed value, if any.
+    pub fn max(&self) -> Option<Decimal<T, SCALE>> {
+        self.max
+    }
+}
+
+/// Iterator over one column of a text buffer, yielding a result per line.
+///
+/// Lines end in `\n` or `\r\n`; a final line break does not start another
+/// row. Fields are parsed with `FromStr` unless `ParseOptions` are given.
+#[derive(Debug, Clone)]
+pub struct ColumnReader<'a, T, const SCALE: u32> {
+    rest: &'a [u8],
+    line: usize,
+    layout: ColumnLayout,
+    options: Option<ParseOptions>,
+    stats: ColumnStats<T, SCALE>,
+}
+
+impl<'a, T: DecimalInt, const SCALE: u32> ColumnReader<'a, T, SCALE> {
+    /// Reader using the strict `FromStr` grammar.
+    pub fn new(input: &'a [u8], layout: ColumnLayout) -> Self {
+        ColumnReader {
+            rest: input,
+            line: 0,
+            layout,
+            options: None,
+            stats: ColumnStats::new(),
+        }
+    }
+
+    /// Reader using `Decimal::parse_with` and `options`.
+    pub fn with_options(input: &'a [u8], layout: ColumnLayout, options: ParseOptions) -> Self {
+        ColumnReader {
+            options: Some(options),
+            ..Self::new(input, layout)
+        }
+    }
+
+    /// Skip `count` lines, such as a header, without parsing or counting them.
+    pub fn skip_lines(mut self, count: usize) -> Self {
+        for _ in 0..count {
+            if self.next_line().is_none() {
+                break;
+            }
+        }
+        self
+    }
+
+    /// Statistics over the rows read so far.
+    pub fn stats(&self) -> &ColumnStats<T, SCALE> {
+        &self.stats
+    }
+
+    /// Read the remaining rows and return the statistics for all of them.
+    pub fn finish(mut self) -> ColumnStats<T, SCALE> {
+        for _ in self.by_ref() {}
+        self.stats
+    }
+
+    fn next_line(&mut self) -> Option<&'a [u8]> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let (line, rest) = match self.rest.iter().position(|&byte| byte == b'\n') {
+            Some(end) => (&self.rest[..end], &self.rest[end + 1..]),
+            None => (self.rest, &[][..]),
+        };
+        self.rest = rest;
+        self.line += 1;
+        Some(line.strip_suffix(b"\r").unwrap_or(line))
+    }
+
+    fn parse(&self, line: &[u8]) -> Result<Decimal<T, SCALE>, ColumnError> {
+        let field = match self.layout {
+            ColumnLayout::Delimited { delimiter, index } => delimited_field(line, delimiter, index),
+            ColumnLayout::FixedWidth { start, width } => {
+                (start < line.len()).then(|| (start, &line[start..line.len().min(start + width)]))
+            }
+        };
+        let Some((start, field)) = field else {
+            return Err(ColumnError {
+                line: self.line,
+                column: line.len() + 1,
+                error: DecimalError::Truncated,
+            });
+        };
+
+        let value = match self.options {
+            Some(options) => Decimal::parse_with(field, options),
+            None => std::str::from_utf8(field)
+                .map_err(|_| DecimalError::Invalid)
+                .and_then(str::parse),
+        };
+        value.map_err(|error| ColumnError {
+            line: self.line,
+            column: start + 1,
+            error,
+        })
+    }
+}
+
+impl<T: DecimalInt, const SCALE: u32> Iterator for ColumnReader<'_, T, SCALE> {
+    type Item = Result<Decimal<T, SCALE>, ColumnError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let line = self.next_line()?;
+        let row = self.parse(line);
+        self.stats.record(&row);
+        Some(row)
+    }
+}
+
+/// Start and contents of field `index`, without surrounding quotes.
+fn delimited_field(line: &[u8], delimiter: u8, index: usize) -> Option<(usize, &[u8])> {
+    let mut start = 0;
+    for _ in 0..index {
+        let end = field_end(line, start, delimiter);
+        if end == line.len() {
+            return None;
+        }
+        start = end + 1;
+    }
+    let field = &line[start..field_end(line, start, delimiter)];
+    match field {
+        [b'"', inner @ .., b'"'] => Some((start + 1, inner)),
+        _ => Some((start, field)),
+    }
+}
+
+/// Index of the delimiter ending the field at `start`, or the line length.
+fn field_end(line: &[u8], start: usize, delimiter: u8) -> usize {
+    let mut at = start;
+    if line.get(start) == Some(&b'"') {
+        // Skip to the closing quote; `""` is an escaped quote.
+        at += 1;
+        while at < line.len() {
+            match (line[at], line.get(at + 1)) {
+                (b'"', Some(b'"')) => at += 2,
+                (b'"', _) => break,
+                _ => at += 1,
+            }
+        }
+    }
+    line[at.min(line.len())..]
+        .iter()
+        .position(|&byte| byte == delimiter)
+        .map_or(line.len(), |offset| at + offset)
+}
@@ -0,0 +1,243 @@
+//! Day-count conventions and accrued interest.
+//!
+//! Year fractions are exact ratios of whole days; they are rounded only when
+//! converted to a `Decimal`, and accrued interest is computed from the exact
+//! ratio with a single rounding to the notional's scale.
+
+use crate::decimal::{BigInt, BigUint, round_quotient, round_ratio, signed_result};
+use crate::{Decimal, DecimalError, DecimalInt, RoundingMode};
+
+/// A proleptic Gregorian calendar date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl Date {
+    /// Returns `Invalid` if the day does not exist in that month.
+    pub fn new(year: i32, month: u32, day: u32) -> Result<Self, DecimalError> {
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return Err(DecimalError::Invalid);
+        }
+        Ok(Date { year, month, day })
+    }
+
+    pub fn year(self) -> i32 {
+        self.year
+    }
+
+    pub fn month(self) -> u32 {
+        self.month
+    }
+
+    pub fn day(self) -> u32 {
+        self.day
+    }
+
+    /// Actual calendar days from `self` to `other`; negative if `other` is earlier.
+    pub fn days_until(self, other: Date) -> i64 {
+        other.day_number() - self.day_number()
+    }
+
+    fn is_last_day_of_month(self) -> bool {
+        self.day == days_in_month(self.year, self.month)
+    }
+
+    fn is_last_day_of_february(self) -> bool {
+        self.month == 2 && self.is_last_day_of_month()
+    }
+
+    /// Days since 1970-01-01 (Howard Hinnant's `days_from_civil`).
+    fn day_number(self) -> i64 {
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = i64::from(self.month);
+        let day_of_year =
+            (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+}
+
+/// Convention for turning a date range into a fraction of a year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DayCount {
+    /// Actual days over 360.
+    Act360,
+    /// Actual days over 365.
+    Act365Fixed,
+    /// 30/360 US (bond basis) with the end-of-February adjustments.
+    Thirty360Us,
+    /// 30E/360 (Eurobond basis).
+    Thirty360European,
+    /// 30E/360 ISDA; a February `termination` end date is left unadjusted.
+    Thirty360Isda { termination: Date },
+    /// Actual days, split by calendar year over 365 or 366.
+    ActActIsda,
+    /// Actual days over `frequency` times the days in the regular coupon
+    /// period `[period_start, period_end]` that contains the accrual; dates
+    /// outside the period are `Invalid`.
+    ActActIcma {
+        period_start: Date,
+        period_end: Date,
+        frequency: u32,
+    },
+}
+
+impl DayCount {
+    /// Exact year fraction from `start` to `end` as `(numerator, denominator)`.
+    ///
+    /// The denominator is always positive; the fraction is negative when
+    /// `end` precedes `start`.
+    pub fn ratio(self, start: Date, end: Date) -> Result<(i64, i64), DecimalError> {
+        match self {
+            DayCount::Act360 => Ok((start.days_until(end), 360)),
+            DayCount::Act365Fixed => Ok((start.days_until(end), 365)),
+            DayCount::Thirty360Us => {
+                let mut d1 = start.day;
+                let mut d2 = end.day;
+                if start.is_last_day_of_february() {
+                    if end.is_last_day_of_february() {
+                        d2 = 30;
+                    }
+                    d1 = 30;
+                }
+                if d2 == 31 && d1 >= 30 {
+                    d2 = 30;
+                }
+                if d1 == 31 {
+                    d1 = 30;
+                }
+                Ok((thirty_360_days(start, d1, end, d2), 360))
+            }
+            DayCount::Thirty360European => {
+                let d1 = start.day.min(30);
+                let d2 = end.day.min(30);
+                Ok((thirty_360_days(start, d1, end, d2), 360))
+            }
+            DayCount::Thirty360Isda { termination } => {
+                let d1 = if start.is_last_day_of_month() {
+                    30
+                } else {
+                    start.day
+                };
+                let d2 = if end.is_last_day_of_month() && !(end == termination && end.month == 2) {
+                    30
+                } else {
+                    end.day
+                };
+                Ok((thirty_360_days(start, d1, end, d2), 360))
+            }
+            DayCount::ActActIsda => {
+                if end < start {
+                    let (numerator, denominator) = self.ratio(end, start)?;
+                    return Ok((-numerator, denominator));
+                }
+                // Days falling in common and in leap years, over 365 and 366.
+                let (mut common, mut leap) = (0, 0);
+                let mut tally = |year, days| {
+                    if is_leap_year(year) {
+                        leap += days;
+                    } else {
+                        common += days;
+                    }
+                };
+                if start.year == end.year {
+                    tally(start.year, start.days_until(end));
+                } else {
+                    // Partial first and last years; the whole years between
+                    // are counted in closed form.
+                    let first_end = Date::new(start.year + 1, 1, 1)?;
+                    let last_start = Date::new(end.year, 1, 1)?;
+                    tally(start.year, start.days_until(first_end));
+                    tally(end.year, last_start.days_until(end));
+                    let whole = i64::from(end.year) - i64::from(start.year) - 1;
+                    let leap_years =
+                        leap_years_through(end.year - 1) - leap_years_through(start.year);
+                    leap += 366 * leap_years;
+                    common += 365 * (whole - leap_years);
+                }
+                Ok((common * 366 + leap * 365, 365 * 366))
+            }
+            DayCount::ActActIcma {
+                period_start,
+                period_end,
+                frequency,
+            } => {
+                let period = period_start.days_until(period_end);
+                let within = |date| (period_start..=period_end).contains(&date);
+                if frequency == 0 || period <= 0 || !within(start) || !within(end) {
+                    return Err(DecimalError::Invalid);
+                }
+                Ok((start.days_until(end), i64::from(frequency) * period))
+            }
+        }
+    }
+
+    /// Year fraction from `start` to `end`, rounded to `OUT`.
+    pub fn year_fraction<T: DecimalInt, const OUT: u32>(
+        self,
+        start: Date,
+        end: Date,
+        mode: RoundingMode,
+    ) -> Result<Decimal<T, OUT>, DecimalError> {
+        let (numerator, denominator) = self.ratio(start, end)?;
+        let scaled = i128::from(numerator)
+            .checked_mul(10_i128.checked_pow(OUT).ok_or(DecimalError::Overflow)?)
+            .ok_or(DecimalError::Overflow)?;
+        Decimal::from_i128(round_quotient(scaled, i128::from(denominator), mode)?)
+    }
+
+    /// Interest accrued on `notional` at the annual `rate` from `start` to
+    /// `end`, rounded once to the notional's scale.
+    pub fn accrued_interest<T, const SCALE: u32, const RATE: u32>(
+        self,
+        notional: Decimal<T, SCALE>,
+        rate: Decimal<T, RATE>,
+        start: Date,
+        end: Date,
+        mode: RoundingMode,
+    ) -> Result<Decimal<T, SCALE>, DecimalError>
+    where
+        T: DecimalInt,
+    {
+        let (numerator, denominator) = self.ratio(start, end)?;
+        let product = BigInt::from_i128(notional.minor_units().to_i128())
+            .mul(&BigInt::from_i128(rate.minor_units().to_i128()))
+            .mul(&BigInt::from_i128(i128::from(numerator)));
+        let divisor =
+            BigUint::pow10(RATE).mul(&BigUint::from_u128(denominator.unsigned_abs().into()));
+        let negative = product.is_negative();
+        let magnitude = round_ratio(product.magnitude(), &divisor, mode.for_magnitude(negative));
+        signed_result(magnitude, negative)
+    }
+}
+
+/// `360·Δyears + 30·Δmonths + (d2 - d1)` with the convention's adjusted days.
+fn thirty_360_days(start: Date, d1: u32, end: Date, d2: u32) -> i64 {
+    360 * (i64::from(end.year) - i64::from(start.year))
+        + 30 * (i64::from(end.month) - i64::from(start.month))
+        + (i64::from(d2) - i64::from(d1))
+}
+
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Leap-year count such that `f(b) - f(a)` is the number in `a + 1..=b`.
+fn leap_years_through(year: i32) -> i64 {
+    let year = i64::from(year);
+    year.div_euclid(4) - year.div_euclid(100) + year.div_euclid(400)
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
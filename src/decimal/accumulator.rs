@@ -0,0 +1,147 @@
+use std::marker::PhantomData;
+
+use crate::decimal::wide::I256;
+use crate::decimal::{Decimal, DecimalError, DecimalInt, RoundingMode};
+
+/// Running total of `Decimal<T, SCALE>` values held in a 256-bit integer.
+///
+/// Totals may exceed the range of `T` (including `i128`) while accumulating;
+/// only the final `sum` or `mean` is narrowed back to `Decimal<T, SCALE>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecimalAccumulator<T, const SCALE: u32> {
+    total: I256,
+    count: u64,
+    _backing: PhantomData<T>,
+}
+
+impl<T: DecimalInt, const SCALE: u32> DecimalAccumulator<T, SCALE> {
+    /// Empty accumulator.
+    pub fn new() -> Self {
+        Self {
+            total: I256::ZERO,
+            count: 0,
+            _backing: PhantomData,
+        }
+    }
+
+    /// Add a value and count it as one entry.
+    ///
+    /// # Panics
+    /// Panics if the 256-bit total overflows.
+    pub fn add(&mut self, value: Decimal<T, SCALE>) {
+        self.total = self
+            .total
+            .checked_add(I256::from_i128(value.minor_units.to_i128()))
+            .expect("decimal accumulator overflowed");
+        self.count += 1;
+    }
+
+    /// Retract a previously added value, removing it from the total and
+    /// the count.
+    ///
+    /// # Panics
+    /// Panics if the accumulator is empty or the 256-bit total overflows.
+    pub fn sub(&mut self, value: Decimal<T, SCALE>) {
+        assert!(self.count != 0, "decimal accumulator is empty");
+        self.total = self
+            .total
+            .checked_sub(I256::from_i128(value.minor_units.to_i128()))
+            .expect("decimal accumulator overflowed");
+        self.count -= 1;
+    }
+
+    /// Combine with another partial accumulator, e.g. from a parallel reduction.
+    ///
+    /// # Panics
+    /// Panics if the 256-bit total overflows.
+    pub fn merge(&mut self, other: Self) {
+        self.total = self
+            .total
+            .checked_add(other.total)
+            .expect("decimal accumulator overflowed");
+        self.count += other.count;
+    }
+
+    /// Number of entries added so far.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Check whether no entries have been added.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Total narrowed back to `Decimal<T, SCALE>`; fails if it does not fit.
+    pub fn sum(&self) -> Result<Decimal<T, SCALE>, DecimalError> {
+        let total = self.total.to_i128().ok_or(DecimalError::Overflow)?;
+        Decimal::from_i128(total)
+    }
+
+    /// Arithmetic mean at `SCALE`, rounded with the provided mode.
+    ///
+    /// Returns `DivisionByZero` when no entries have been added.
+    pub fn mean(&self, mode: RoundingMode) -> Result<Decimal<T, SCALE>, DecimalError> {
+        if self.count == 0 {
+            return Err(DecimalError::DivisionByZero);
+        }
+
+        let (base, rem) = self.total.div_rem_u64(self.count);
+        let base = base.to_i128().ok_or(DecimalError::Overflow)?;
+        if rem == 0 {
+            return Decimal::from_i128(base);
+        }
+        let twice = u128::from(rem) * 2;
+        let count = u128::from(self.count);
+        let should_round = match mode {
+            RoundingMode::Truncate => false,
+            RoundingMode::Floor => self.total.is_negative(),
+            RoundingMode::Ceiling => !self.total.is_negative(),
+            RoundingMode::HalfUp => twice >= count,
+            RoundingMode::HalfEven => {
+                if twice > count {
+                    true
+                } else if twice < count {
+                    false
+                } else {
+                    base % 2 != 0
+                }
+            }
+        };
+
+        let rounded = if should_round {
+            if self.total.is_negative() {
+                base.checked_sub(1).ok_or(DecimalError::Overflow)?
+            } else {
+                base.checked_add(1).ok_or(DecimalError::Overflow)?
+            }
+        } else {
+            base
+        };
+        Decimal::from_i128(rounded)
+    }
+}
+
+impl<T: DecimalInt, const SCALE: u32> Default for DecimalAccumulator<T, SCALE> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: DecimalInt, const SCALE: u32> Extend<Decimal<T, SCALE>> for DecimalAccumulator<T, SCALE> {
+    fn extend<I: IntoIterator<Item = Decimal<T, SCALE>>>(&mut self, iter: I) {
+        for value in iter {
+            self.add(value);
+        }
+    }
+}
+
+impl<T: DecimalInt, const SCALE: u32> FromIterator<Decimal<T, SCALE>>
+    for DecimalAccumulator<T, SCALE>
+{
+    fn from_iter<I: IntoIterator<Item = Decimal<T, SCALE>>>(iter: I) -> Self {
+        let mut accumulator = Self::new();
+        accumulator.extend(iter);
+        accumulator
+    }
+}
@@ -0,0 +1,157 @@
+use crate::decimal::{Decimal, DecimalError, DecimalInt};
+
+/// How leftover minor units are distributed after proportional allocation.
+///
+/// Only parts with a non-zero weight ever receive a leftover unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AllocationStrategy {
+    /// Parts with the largest fractional remainders first (Hamilton's method).
+    LargestRemainder,
+    /// Parts in order from the first.
+    FirstN,
+    /// Consecutive parts starting at `start`, wrapping around.
+    RoundRobin { start: usize },
+    /// Parts chosen by a shuffle seeded with `seed`; the same seed gives the same split.
+    Random { seed: u64 },
+}
+
+impl<T: DecimalInt, const SCALE: u32> Decimal<T, SCALE> {
+    /// Split proportionally to `weights`; the parts always sum to `self`.
+    ///
+    /// Weights must be non-negative with a positive total. Negative amounts are
+    /// split as their magnitude and every part carries the sign.
+    pub fn allocate<const WEIGHT: u32>(
+        self,
+        weights: &[Decimal<T, WEIGHT>],
+        strategy: AllocationStrategy,
+    ) -> Result<Vec<Self>, DecimalError> {
+        if weights.is_empty() {
+            return Err(DecimalError::Empty);
+        }
+
+        let mut total_weight = 0_i128;
+        let mut raw_weights = Vec::with_capacity(weights.len());
+        for weight in weights {
+            let weight = weight.minor_units.to_i128();
+            if weight < 0 {
+                return Err(DecimalError::Invalid);
+            }
+            total_weight = total_weight
+                .checked_add(weight)
+                .ok_or(DecimalError::Overflow)?;
+            raw_weights.push(weight);
+        }
+        if total_weight == 0 {
+            return Err(DecimalError::DivisionByZero);
+        }
+
+        let amount = self.minor_units.to_i128();
+        let magnitude = amount.checked_abs().ok_or(DecimalError::Overflow)?;
+        let mut shares = Vec::with_capacity(raw_weights.len());
+        for weight in raw_weights {
+            let scaled = magnitude
+                .checked_mul(weight)
+                .ok_or(DecimalError::Overflow)?;
+            shares.push(Share {
+                base: scaled / total_weight,
+                remainder: scaled % total_weight,
+                eligible: weight > 0,
+            });
+        }
+
+        distribute(amount, shares, strategy)
+    }
+
+    /// Split into `parts` near-equal values that sum to `self`.
+    ///
+    /// Returns `Invalid` if `parts` exceeds 2^20, whatever the amount; the
+    /// bound caps the working buffers and the output, not just the zero parts.
+    pub fn split_evenly(
+        self,
+        parts: usize,
+        strategy: AllocationStrategy,
+    ) -> Result<Vec<Self>, DecimalError> {
+        if parts == 0 {
+            return Err(DecimalError::DivisionByZero);
+        }
+
+        if parts > MAX_SPLIT_PARTS {
+            return Err(DecimalError::Invalid);
+        }
+
+        let amount = self.minor_units.to_i128();
+        let magnitude = amount.checked_abs().ok_or(DecimalError::Overflow)?;
+        let count = parts as i128;
+
+        let share = Share {
+            base: magnitude / count,
+            remainder: magnitude % count,
+            eligible: true,
+        };
+        distribute(amount, vec![share; parts], strategy)
+    }
+}
+
+/// Most parts `split_evenly` will produce.
+const MAX_SPLIT_PARTS: usize = 1 << 20;
+
+#[derive(Debug, Clone, Copy)]
+struct Share {
+    base: i128,
+    remainder: i128,
+    eligible: bool,
+}
+
+/// Hand out the units lost to flooring and restore the sign of `amount`.
+fn distribute<T: DecimalInt, const SCALE: u32>(
+    amount: i128,
+    mut shares: Vec<Share>,
+    strategy: AllocationStrategy,
+) -> Result<Vec<Decimal<T, SCALE>>, DecimalError> {
+    let allocated: i128 = shares.iter().map(|share| share.base).sum();
+    let leftover = amount.abs() - allocated;
+
+    let mut candidates: Vec<usize> = (0..shares.len())
+        .filter(|&index| shares[index].eligible)
+        .collect();
+    match strategy {
+        AllocationStrategy::LargestRemainder => {
+            candidates.sort_by(|&a, &b| shares[b].remainder.cmp(&shares[a].remainder));
+        }
+        AllocationStrategy::FirstN => {}
+        AllocationStrategy::RoundRobin { start } => {
+            let start = start % shares.len();
+            let split = candidates.partition_point(|&index| index < start);
+            candidates.rotate_left(split);
+        }
+        AllocationStrategy::Random { seed } => {
+            let mut state = seed;
+            for i in (1..candidates.len()).rev() {
+                let j = (split_mix64(&mut state) % (i as u64 + 1)) as usize;
+                candidates.swap(i, j);
+            }
+        }
+    }
+
+    // Leftover is below the number of eligible parts, so one pass suffices.
+    for &index in candidates.iter().take(leftover as usize) {
+        shares[index].base += 1;
+    }
+
+    shares
+        .into_iter()
+        .map(|share| {
+            let value = if amount < 0 { -share.base } else { share.base };
+            Decimal::from_i128(value)
+        })
+        .collect()
+}
+
+/// SplitMix64 step; small, deterministic and good enough for tie-breaking.
+fn split_mix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
@@ -1,6 +1,6 @@
 use std::ops::{Add, AddAssign, Sub, SubAssign};
 
-use crate::decimal::{Decimal, DecimalError, DecimalInt, RoundingMode};
+use crate::decimal::{Decimal, DecimalError, DecimalInt, RoundingMode, ScaleSum};
 
 impl<T: DecimalInt, const SCALE: u32> Add for Decimal<T, SCALE> {
     type Output = Self;
@@ -29,15 +29,15 @@ impl<T: DecimalInt, const SCALE: u32> Decimal<T, SCALE> {
 
     /// Check whether this value is zero.
     pub fn is_zero(&self) -> bool {
-        self.minor_units == T::try_from_i128(0).unwrap_or_else(|| unreachable!())
+        self.minor_units == T::ZERO
     }
 
     pub fn is_negative(&self) -> bool {
-        self.minor_units < T::try_from_i128(0).unwrap_or_else(|| unreachable!())
+        self.minor_units < T::ZERO
     }
 
     pub fn is_positive(&self) -> bool {
-        self.minor_units >= T::try_from_i128(0).unwrap_or_else(|| unreachable!())
+        self.minor_units >= T::ZERO
     }
 
     /// Multiply and rescale to the output scale with rounding.
@@ -76,6 +76,8 @@ impl<T: DecimalInt, const SCALE: u32> Decimal<T, SCALE> {
         let abs_factor = factor.abs();
         let should_round = match mode {
             RoundingMode::Truncate => false,
+            RoundingMode::Floor => product.is_negative(),
+            RoundingMode::Ceiling => !product.is_negative(),
             RoundingMode::HalfUp => abs_rem * 2 >= abs_factor,
             RoundingMode::HalfEven => {
                 let twice = abs_rem * 2;
@@ -102,6 +104,24 @@ impl<T: DecimalInt, const SCALE: u32> Decimal<T, SCALE> {
         Decimal::<T, OUT>::from_i128(rounded)
     }
 
+    /// Multiply exactly, producing a value at scale `SCALE + RHS`.
+    ///
+    /// No digits are dropped; only an explicit `rescale` of the result rounds.
+    pub fn mul_exact<const RHS: u32>(
+        self,
+        rhs: Decimal<T, RHS>,
+    ) -> Result<<Self as ScaleSum<RHS>>::Output, DecimalError>
+    where
+        Self: ScaleSum<RHS>,
+    {
+        let product = self
+            .minor_units
+            .to_i128()
+            .checked_mul(rhs.minor_units.to_i128())
+            .ok_or(DecimalError::Overflow)?;
+        <Self as ScaleSum<RHS>>::from_product(product)
+    }
+
     /// Multiply by a rate and keep the current scale.
     pub fn mul<const RATE: u32>(
         self,
@@ -142,8 +162,11 @@ impl<T: DecimalInt, const SCALE: u32> Decimal<T, SCALE> {
 
         let abs_rem = rem.abs();
         let abs_den = denominator.abs();
+        let negative = (numerator < 0) ^ (denominator < 0);
         let should_round = match mode {
             RoundingMode::Truncate => false,
+            RoundingMode::Floor => negative,
+            RoundingMode::Ceiling => !negative,
             RoundingMode::HalfUp => abs_rem * 2 >= abs_den,
             RoundingMode::HalfEven => {
                 let twice = abs_rem * 2;
@@ -158,7 +181,7 @@ impl<T: DecimalInt, const SCALE: u32> Decimal<T, SCALE> {
         };
 
         let rounded = if should_round {
-            if (numerator < 0) ^ (denominator < 0) {
+            if negative {
                 base.checked_sub(1).ok_or(DecimalError::Overflow)?
             } else {
                 base.checked_add(1).ok_or(DecimalError::Overflow)?
@@ -0,0 +1,179 @@
+use arrow_array::types::DecimalType;
+use arrow_array::{Array, Decimal128Array, Decimal256Array, PrimitiveArray};
+use arrow_buffer::i256;
+
+use crate::decimal::{
+    BigUint, Decimal, DecimalError, DecimalInt, RoundingMode, round_quotient, round_ratio,
+    signed_result,
+};
+
+impl<const SCALE: u32> Decimal<i128, SCALE> {
+    /// Borrow the values of a `Decimal128` array without copying.
+    ///
+    /// Returns `ScaleMismatch` unless the array's scale is `SCALE` and
+    /// `Invalid` if the array has nulls, whose slots hold no value.
+    pub fn slice_from_arrow(array: &Decimal128Array) -> Result<&[Self], DecimalError> {
+        check_scale(array.scale(), SCALE)?;
+        if array.null_count() > 0 {
+            return Err(DecimalError::Invalid);
+        }
+        let values: &[i128] = array.values();
+        // SAFETY: `Decimal<i128, SCALE>` is `repr(transparent)` over `i128`,
+        // and every `i128` is a valid value.
+        Ok(unsafe { std::slice::from_raw_parts(values.as_ptr().cast::<Self>(), values.len()) })
+    }
+
+    /// Build a `Decimal128` array that takes over the allocation of `values`.
+    ///
+    /// Returns `Invalid` for a precision outside `1..=38` or below `SCALE`,
+    /// and `Overflow` if a value has more than `precision` digits.
+    pub fn into_arrow(values: Vec<Self>, precision: u8) -> Result<Decimal128Array, DecimalError> {
+        // Same size and alignment, so this collects in place.
+        let minor: Vec<i128> = values.into_iter().map(|value| value.minor_units).collect();
+        with_precision(Decimal128Array::from(minor), precision, SCALE)
+    }
+}
+
+impl<T: DecimalInt, const SCALE: u32> Decimal<T, SCALE> {
+    /// Copy a `Decimal128` array, rescaling from its scale to `SCALE`.
+    ///
+    /// Nulls become `None`. Extra decimals are rounded with `mode`.
+    pub fn from_arrow128(
+        array: &Decimal128Array,
+        mode: RoundingMode,
+    ) -> Result<Vec<Option<Self>>, DecimalError> {
+        collect(array, |value| {
+            rescale_i128::<SCALE>(value, array.scale(), mode)
+        })
+    }
+
+    /// Copy a `Decimal256` array, rescaling from its scale to `SCALE`.
+    ///
+    /// Nulls become `None`. Extra decimals are rounded with `mode`.
+    pub fn from_arrow256(
+        array: &Decimal256Array,
+        mode: RoundingMode,
+    ) -> Result<Vec<Option<Self>>, DecimalError> {
+        collect(array, |value| {
+            rescale_i256::<SCALE>(value, array.scale(), mode)
+        })
+    }
+
+    /// Build a `Decimal128(precision, SCALE)` array from `values`.
+    ///
+    /// Errors as `into_arrow`.
+    pub fn to_arrow128(values: &[Self], precision: u8) -> Result<Decimal128Array, DecimalError> {
+        let minor: Vec<i128> = values
+            .iter()
+            .map(|value| value.minor_units.to_i128())
+            .collect();
+        with_precision(Decimal128Array::from(minor), precision, SCALE)
+    }
+
+    /// Build a `Decimal256(precision, SCALE)` array from `values`.
+    ///
+    /// Returns `Invalid` for a precision outside `1..=76` or below `SCALE`,
+    /// and `Overflow` if a value has more than `precision` digits.
+    pub fn to_arrow256(values: &[Self], precision: u8) -> Result<Decimal256Array, DecimalError> {
+        let minor: Vec<i256> = values
+            .iter()
+            .map(|value| i256::from_i128(value.minor_units.to_i128()))
+            .collect();
+        with_precision(Decimal256Array::from(minor), precision, SCALE)
+    }
+}
+
+/// Arrow scales may be negative; those never match a `Decimal`.
+fn check_scale(found: i8, expected: u32) -> Result<(), DecimalError> {
+    let found = u32::try_from(found).map_err(|_| DecimalError::Invalid)?;
+    if found != expected {
+        return Err(DecimalError::ScaleMismatch { expected, found });
+    }
+    Ok(())
+}
+
+/// Attach precision and scale, then check every value fits the precision.
+fn with_precision<D: DecimalType>(
+    array: PrimitiveArray<D>,
+    precision: u8,
+    scale: u32,
+) -> Result<PrimitiveArray<D>, DecimalError> {
+    let scale = i8::try_from(scale).map_err(|_| DecimalError::Invalid)?;
+    let array = array
+        .with_precision_and_scale(precision, scale)
+        .map_err(|_| DecimalError::Invalid)?;
+    array
+        .validate_decimal_precision(precision)
+        .map_err(|_| DecimalError::Overflow)?;
+    Ok(array)
+}
+
+fn collect<D: DecimalType, T: DecimalInt, const SCALE: u32>(
+    array: &PrimitiveArray<D>,
+    mut rescale: impl FnMut(D::Native) -> Result<i128, DecimalError>,
+) -> Result<Vec<Option<Decimal<T, SCALE>>>, DecimalError> {
+    array
+        .iter()
+        .map(|value| {
+            value
+                .map(|value| Decimal::from_i128(rescale(value)?))
+                .transpose()
+        })
+        .collect()
+}
+
+/// Minor units at `SCALE` for `value` at Arrow scale `from`.
+fn rescale_i128<const SCALE: u32>(
+    value: i128,
+    from: i8,
+    mode: RoundingMode,
+) -> Result<i128, DecimalError> {
+    if value == 0 {
+        return Ok(0);
+    }
+    let shift = i64::from(SCALE) - i64::from(from);
+    let factor = 10_i128
+        .checked_pow(shift.unsigned_abs() as u32)
+        .ok_or(DecimalError::Overflow);
+    if shift >= 0 {
+        value.checked_mul(factor?).ok_or(DecimalError::Overflow)
+    } else {
+        match factor {
+            Ok(factor) => round_quotient(value, factor, mode),
+            // Dividing by more than 10^38 leaves only the rounding direction.
+            Err(_) => rescale_wide(i256::from_i128(value), shift, mode),
+        }
+    }
+}
+
+fn rescale_i256<const SCALE: u32>(
+    value: i256,
+    from: i8,
+    mode: RoundingMode,
+) -> Result<i128, DecimalError> {
+    match value.to_i128() {
+        Some(value) => rescale_i128::<SCALE>(value, from, mode),
+        None => rescale_wide(value, i64::from(SCALE) - i64::from(from), mode),
+    }
+}
+
+/// `value · 10^shift` rounded to an integer, computed exactly.
+fn rescale_wide(value: i256, shift: i64, mode: RoundingMode) -> Result<i128, DecimalError> {
+    let negative = value.is_negative();
+    let magnitude = if negative {
+        value.wrapping_neg()
+    } else {
+        value
+    };
+    let (low, high) = magnitude.to_parts();
+    let magnitude = BigUint::from_u128(high as u128)
+        .shl(128)
+        .add(&BigUint::from_u128(low));
+    let factor = BigUint::pow10(shift.unsigned_abs() as u32);
+    let magnitude = if shift >= 0 {
+        magnitude.mul(&factor)
+    } else {
+        round_ratio(&magnitude, &factor, mode.for_magnitude(negative))
+    };
+    Ok(signed_result::<i128, 0>(magnitude, negative)?.minor_units)
+}
@@ -0,0 +1,406 @@
+use std::cmp::Ordering;
+
+/// Arbitrary-precision unsigned integer for exact intermediate results.
+///
+/// `mul_rescale` and `I256` bound every intermediate, but a correctly
+/// rounded `x^n` or n-th root needs operands whose size grows with `n` and
+/// the working precision (e.g. `d^n` against `10^(n·SCALE)`), so those paths
+/// and the exact statistics use this type instead.
+///
+/// Stored as little-endian `u32` limbs with no trailing zero limbs, so equal
+/// values always have equal representations.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub(crate) struct BigUint {
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+    pub(crate) fn zero() -> Self {
+        Self { limbs: Vec::new() }
+    }
+
+    pub(crate) fn from_u128(mut value: u128) -> Self {
+        let mut limbs = Vec::new();
+        while value != 0 {
+            limbs.push(value as u32);
+            value >>= 32;
+        }
+        Self { limbs }
+    }
+
+    /// `10^exp`.
+    pub(crate) fn pow10(exp: u32) -> Self {
+        let mut value = Self::from_u128(1);
+        let mut remaining = exp;
+        while remaining >= 9 {
+            value.mul_small(1_000_000_000);
+            remaining -= 9;
+        }
+        value.mul_small(10_u32.pow(remaining));
+        value
+    }
+
+    pub(crate) fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// Narrow to `u128` if the value fits.
+    pub(crate) fn to_u128(&self) -> Option<u128> {
+        if self.limbs.len() > 4 {
+            return None;
+        }
+        Some(
+            self.limbs
+                .iter()
+                .rev()
+                .fold(0_u128, |acc, &limb| (acc << 32) | u128::from(limb)),
+        )
+    }
+
+    pub(crate) fn is_odd(&self) -> bool {
+        self.limbs.first().is_some_and(|limb| limb & 1 == 1)
+    }
+
+    /// Number of significant bits.
+    pub(crate) fn bits(&self) -> u64 {
+        match self.limbs.last() {
+            Some(&top) => self.limbs.len() as u64 * 32 - u64::from(top.leading_zeros()),
+            None => 0,
+        }
+    }
+
+    fn set_bit(&mut self, index: u64) {
+        let limb = (index / 32) as usize;
+        if limb >= self.limbs.len() {
+            self.limbs.resize(limb + 1, 0);
+        }
+        self.limbs[limb] |= 1 << (index % 32);
+    }
+
+    fn normalize(&mut self) {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+    }
+
+    pub(crate) fn mul_small(&mut self, rhs: u32) {
+        let mut carry = 0_u64;
+        for limb in self.limbs.iter_mut() {
+            let product = u64::from(*limb) * u64::from(rhs) + carry;
+            *limb = product as u32;
+            carry = product >> 32;
+        }
+        if carry != 0 {
+            self.limbs.push(carry as u32);
+        }
+        self.normalize();
+    }
+
+    pub(crate) fn add(&self, rhs: &Self) -> Self {
+        let (long, short) = if self.limbs.len() >= rhs.limbs.len() {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+        let mut limbs = Vec::with_capacity(long.limbs.len() + 1);
+        let mut carry = 0_u64;
+        for (i, &limb) in long.limbs.iter().enumerate() {
+            let sum = u64::from(limb) + u64::from(*short.limbs.get(i).unwrap_or(&0)) + carry;
+            limbs.push(sum as u32);
+            carry = sum >> 32;
+        }
+        if carry != 0 {
+            limbs.push(carry as u32);
+        }
+        Self { limbs }
+    }
+
+    /// `self - rhs`; the caller guarantees `self >= rhs`.
+    pub(crate) fn sub(&self, rhs: &Self) -> Self {
+        debug_assert!(*self >= *rhs);
+        let mut limbs = Vec::with_capacity(self.limbs.len());
+        let mut borrow = 0_i64;
+        for (i, &limb) in self.limbs.iter().enumerate() {
+            let mut diff = i64::from(limb) - i64::from(*rhs.limbs.get(i).unwrap_or(&0)) - borrow;
+            borrow = 0;
+            if diff < 0 {
+                diff += 1 << 32;
+                borrow = 1;
+            }
+            limbs.push(diff as u32);
+        }
+        let mut result = Self { limbs };
+        result.normalize();
+        result
+    }
+
+    pub(crate) fn mul(&self, rhs: &Self) -> Self {
+        if self.is_zero() || rhs.is_zero() {
+            return Self::zero();
+        }
+        let mut limbs = vec![0_u32; self.limbs.len() + rhs.limbs.len()];
+        for (i, &a) in self.limbs.iter().enumerate() {
+            let mut carry = 0_u64;
+            for (j, &b) in rhs.limbs.iter().enumerate() {
+                let current = u64::from(limbs[i + j]) + u64::from(a) * u64::from(b) + carry;
+                limbs[i + j] = current as u32;
+                carry = current >> 32;
+            }
+            limbs[i + rhs.limbs.len()] = carry as u32;
+        }
+        let mut result = Self { limbs };
+        result.normalize();
+        result
+    }
+
+    pub(crate) fn pow(&self, mut exp: u32) -> Self {
+        let mut result = Self::from_u128(1);
+        let mut base = self.clone();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.mul(&base);
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.mul(&base);
+            }
+        }
+        result
+    }
+
+    /// `self * 2^shift`.
+    pub(crate) fn shl(&self, shift: u64) -> Self {
+        if self.is_zero() {
+            return Self::zero();
+        }
+        let limb_shift = (shift / 32) as usize;
+        let bit_shift = (shift % 32) as u32;
+        let mut limbs = vec![0_u32; limb_shift];
+        let mut carry = 0_u32;
+        for &limb in &self.limbs {
+            if bit_shift == 0 {
+                limbs.push(limb);
+            } else {
+                limbs.push((limb << bit_shift) | carry);
+                carry = limb >> (32 - bit_shift);
+            }
+        }
+        if carry != 0 {
+            limbs.push(carry);
+        }
+        Self { limbs }
+    }
+
+    /// Quotient and remainder; the caller guarantees a non-zero divisor.
+    ///
+    /// Schoolbook long division (Knuth, TAOCP vol. 2, algorithm D).
+    pub(crate) fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        debug_assert!(!divisor.is_zero());
+        if *self < *divisor {
+            return (Self::zero(), self.clone());
+        }
+        if divisor.limbs.len() == 1 {
+            return self.div_rem_limb(divisor.limbs[0]);
+        }
+
+        // Normalize so the divisor's top limb has its high bit set.
+        let shift = u64::from(divisor.limbs[divisor.limbs.len() - 1].leading_zeros());
+        let v = divisor.shl(shift).limbs;
+        let mut u = self.shl(shift).limbs;
+        if u.len() == self.limbs.len() {
+            u.push(0);
+        }
+        let n = v.len();
+        let m = u.len() - n - 1;
+        let base = 1_u64 << 32;
+        let mut quotient = vec![0_u32; m + 1];
+
+        for j in (0..=m).rev() {
+            let top = (u64::from(u[j + n]) << 32) | u64::from(u[j + n - 1]);
+            let mut qhat = top / u64::from(v[n - 1]);
+            let mut rhat = top % u64::from(v[n - 1]);
+            while qhat >= base
+                || qhat * u64::from(v[n - 2]) > ((rhat << 32) | u64::from(u[j + n - 2]))
+            {
+                qhat -= 1;
+                rhat += u64::from(v[n - 1]);
+                if rhat >= base {
+                    break;
+                }
+            }
+
+            // Multiply and subtract `qhat * v` from the current window.
+            let mut borrow = 0_i64;
+            for i in 0..n {
+                let product = qhat * u64::from(v[i]);
+                let t = i64::from(u[i + j]) - borrow - (product & 0xFFFF_FFFF) as i64;
+                u[i + j] = t as u32;
+                borrow = (product >> 32) as i64 - (t >> 32);
+            }
+            let t = i64::from(u[j + n]) - borrow;
+            u[j + n] = t as u32;
+
+            quotient[j] = qhat as u32;
+            if t < 0 {
+                // `qhat` was one too large; add the divisor back.
+                quotient[j] = quotient[j].wrapping_sub(1);
+                let mut carry = 0_u64;
+                for i in 0..n {
+                    let sum = u64::from(u[i + j]) + u64::from(v[i]) + carry;
+                    u[i + j] = sum as u32;
+                    carry = sum >> 32;
+                }
+                u[j + n] = u[j + n].wrapping_add(carry as u32);
+            }
+        }
+
+        let mut quotient = Self { limbs: quotient };
+        quotient.normalize();
+        let mut rem = Self {
+            limbs: u[..n].to_vec(),
+        };
+        rem.normalize();
+        (quotient, rem.shr(shift))
+    }
+
+    fn div_rem_limb(&self, divisor: u32) -> (Self, Self) {
+        let mut limbs = vec![0_u32; self.limbs.len()];
+        let mut rem = 0_u64;
+        for i in (0..self.limbs.len()).rev() {
+            let current = (rem << 32) | u64::from(self.limbs[i]);
+            limbs[i] = (current / u64::from(divisor)) as u32;
+            rem = current % u64::from(divisor);
+        }
+        let mut quotient = Self { limbs };
+        quotient.normalize();
+        (quotient, Self::from_u128(u128::from(rem)))
+    }
+
+    /// `self / 2^shift` for `shift < 32`.
+    fn shr(&self, shift: u64) -> Self {
+        if shift == 0 {
+            return self.clone();
+        }
+        let mut limbs = self.limbs.clone();
+        for i in 0..limbs.len() {
+            let high = limbs.get(i + 1).copied().unwrap_or(0);
+            limbs[i] = (limbs[i] >> shift) | (high << (32 - shift));
+        }
+        let mut result = Self { limbs };
+        result.normalize();
+        result
+    }
+
+    /// `floor(self^(1/n))` for `n >= 1`.
+    pub(crate) fn nth_root(&self, n: u32) -> Self {
+        debug_assert!(n >= 1);
+        if n == 1 || self.is_zero() {
+            return self.clone();
+        }
+
+        let mut root = Self::zero();
+        for index in (0..self.bits().div_ceil(u64::from(n))).rev() {
+            let mut candidate = root.clone();
+            candidate.set_bit(index);
+            if candidate.pow(n) <= *self {
+                root = candidate;
+            }
+        }
+        root
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Signed counterpart of `BigUint` for exact rational intermediates.
+///
+/// Zero is never negative, so equal values have equal representations.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub(crate) struct BigInt {
+    negative: bool,
+    magnitude: BigUint,
+}
+
+impl BigInt {
+    pub(crate) fn zero() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn from_i128(value: i128) -> Self {
+        Self::new(value < 0, BigUint::from_u128(value.unsigned_abs()))
+    }
+
+    pub(crate) fn new(negative: bool, magnitude: BigUint) -> Self {
+        let negative = negative && !magnitude.is_zero();
+        Self {
+            negative,
+            magnitude,
+        }
+    }
+
+    pub(crate) fn is_zero(&self) -> bool {
+        self.magnitude.is_zero()
+    }
+
+    pub(crate) fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub(crate) fn magnitude(&self) -> &BigUint {
+        &self.magnitude
+    }
+
+    /// `Less`, `Equal` or `Greater` than zero.
+    pub(crate) fn signum(&self) -> Ordering {
+        if self.negative {
+            Ordering::Less
+        } else if self.is_zero() {
+            Ordering::Equal
+        } else {
+            Ordering::Greater
+        }
+    }
+
+    pub(crate) fn neg(&self) -> Self {
+        Self::new(!self.negative, self.magnitude.clone())
+    }
+
+    pub(crate) fn add(&self, rhs: &Self) -> Self {
+        if self.negative == rhs.negative {
+            return Self::new(self.negative, self.magnitude.add(&rhs.magnitude));
+        }
+        if self.magnitude >= rhs.magnitude {
+            Self::new(self.negative, self.magnitude.sub(&rhs.magnitude))
+        } else {
+            Self::new(rhs.negative, rhs.magnitude.sub(&self.magnitude))
+        }
+    }
+
+    pub(crate) fn sub(&self, rhs: &Self) -> Self {
+        self.add(&rhs.neg())
+    }
+
+    pub(crate) fn mul(&self, rhs: &Self) -> Self {
+        Self::new(
+            self.negative != rhs.negative,
+            self.magnitude.mul(&rhs.magnitude),
+        )
+    }
+
+    pub(crate) fn pow(&self, exp: u32) -> Self {
+        Self::new(self.negative && exp % 2 == 1, self.magnitude.pow(exp))
+    }
+}
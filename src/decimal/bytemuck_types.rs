@@ -0,0 +1,14 @@
+use bytemuck::{Pod, TransparentWrapper, Zeroable};
+
+use crate::decimal::Decimal;
+
+// SAFETY: `Decimal<T, SCALE>` is `repr(transparent)` over `T`, so it has no
+// padding and its bit patterns are exactly those of `T`.
+unsafe impl<T: Zeroable, const SCALE: u32> Zeroable for Decimal<T, SCALE> {}
+
+// SAFETY: as above; every valid `T` is a valid decimal.
+unsafe impl<T: Pod, const SCALE: u32> Pod for Decimal<T, SCALE> {}
+
+/// Lets `wrap_slice` view a `&[T]` of minor units as decimals.
+// SAFETY: `repr(transparent)` over its only field, of type `T`.
+unsafe impl<T, const SCALE: u32> TransparentWrapper<T> for Decimal<T, SCALE> {}
@@ -0,0 +1,102 @@
+use std::cmp::Ordering;
+
+use crate::decimal::conversion::round_quotient;
+use crate::decimal::parsing::{STRICT, parse_minor_units};
+use crate::decimal::{Decimal, RoundingMode};
+
+/// `const fn` counterparts of the generic operations for the built-in backings.
+///
+/// The generic methods go through `DecimalInt`, whose trait methods cannot be
+/// called in const contexts, and an inherent method on `Decimal<i64, SCALE>`
+/// may not share a name with one on `Decimal<T, SCALE>`. The counterparts
+/// therefore carry a `const_` prefix and return `Option` rather than
+/// `Result`, since `Result::unwrap` is not const.
+macro_rules! impl_const_ops {
+    ($($t:ty),+ $(,)?) => {
+        $(
+            impl<const SCALE: u32> Decimal<$t, SCALE> {
+                /// Construct from a whole number; returns `None` on overflow.
+                pub const fn from_integer(value: $t) -> Option<Self> {
+                    match value.checked_mul((10 as $t).pow(SCALE)) {
+                        Some(minor_units) => Some(Self { minor_units }),
+                        None => None,
+                    }
+                }
+
+                /// `checked_add` usable in const contexts; returns `None` on overflow.
+                pub const fn const_checked_add(self, rhs: Self) -> Option<Self> {
+                    match self.minor_units.checked_add(rhs.minor_units) {
+                        Some(minor_units) => Some(Self { minor_units }),
+                        None => None,
+                    }
+                }
+
+                /// `checked_sub` usable in const contexts; returns `None` on overflow.
+                pub const fn const_checked_sub(self, rhs: Self) -> Option<Self> {
+                    match self.minor_units.checked_sub(rhs.minor_units) {
+                        Some(minor_units) => Some(Self { minor_units }),
+                        None => None,
+                    }
+                }
+
+                /// `rescale` usable in const contexts; returns `None` on overflow.
+                pub const fn const_rescale<const TO: u32>(
+                    self,
+                    mode: RoundingMode,
+                ) -> Option<Decimal<$t, TO>> {
+                    let minor_units = self.minor_units as i128;
+                    let scaled = if TO >= SCALE {
+                        minor_units.checked_mul(10_i128.pow(TO - SCALE))
+                    } else {
+                        match round_quotient(minor_units, 10_i128.pow(SCALE - TO), mode) {
+                            Ok(rounded) => Some(rounded),
+                            Err(_) => None,
+                        }
+                    };
+
+                    match scaled {
+                        Some(value)
+                            if value >= <$t>::MIN as i128 && value <= <$t>::MAX as i128 =>
+                        {
+                            Some(Decimal {
+                                minor_units: value as $t,
+                            })
+                        }
+                        _ => None,
+                    }
+                }
+
+                /// `Ord::cmp` usable in const contexts.
+                pub const fn const_cmp(self, rhs: Self) -> Ordering {
+                    if self.minor_units < rhs.minor_units {
+                        Ordering::Less
+                    } else if self.minor_units > rhs.minor_units {
+                        Ordering::Greater
+                    } else {
+                        Ordering::Equal
+                    }
+                }
+
+                /// `PartialEq::eq` usable in const contexts.
+                pub const fn const_eq(self, rhs: Self) -> bool {
+                    self.minor_units == rhs.minor_units
+                }
+
+                /// `FromStr` usable in const contexts, with the same grammar;
+                /// returns `None` where `FromStr` returns an error.
+                pub const fn const_parse(input: &str) -> Option<Self> {
+                    match parse_minor_units(input.as_bytes(), SCALE, STRICT) {
+                        Ok(value) if value >= <$t>::MIN as i128 && value <= <$t>::MAX as i128 => {
+                            Some(Self {
+                                minor_units: value as $t,
+                            })
+                        }
+                        _ => None,
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_const_ops!(i64, i128);
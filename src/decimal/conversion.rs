@@ -1,11 +1,31 @@
-use crate::decimal::{Decimal, DecimalError, DecimalInt};
+use crate::decimal::{BigUint, Decimal, DecimalError, DecimalInt};
 
 /// Rounding modes for rescale and conversion operations.
+///
+/// More modes may be added, so matches outside this crate need a wildcard arm.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum RoundingMode {
     Truncate,
     HalfUp,
     HalfEven,
+    /// Toward negative infinity.
+    Floor,
+    /// Toward positive infinity.
+    Ceiling,
+}
+
+impl RoundingMode {
+    /// The mode that rounds the magnitude of a value with the given sign the
+    /// same way `self` rounds the value; helpers that only see magnitudes
+    /// treat `Floor` as truncation and `Ceiling` as rounding up.
+    pub(crate) const fn for_magnitude(self, negative: bool) -> Self {
+        match (self, negative) {
+            (RoundingMode::Floor, true) => RoundingMode::Ceiling,
+            (RoundingMode::Ceiling, true) => RoundingMode::Floor,
+            (mode, _) => mode,
+        }
+    }
 }
 
 impl<T: DecimalInt, const SCALE: u32> Decimal<T, SCALE> {
@@ -25,8 +45,9 @@ impl<T: DecimalInt, const SCALE: u32> Decimal<T, SCALE> {
         let scaled = value * scale;
         let abs = scaled.abs();
 
-        let rounded_abs = match mode {
-            RoundingMode::Truncate => abs.trunc(),
+        let rounded_abs = match mode.for_magnitude(scaled.is_sign_negative()) {
+            RoundingMode::Truncate | RoundingMode::Floor => abs.trunc(),
+            RoundingMode::Ceiling => abs.ceil(),
             RoundingMode::HalfUp => abs.round(),
             RoundingMode::HalfEven => {
                 let floor = abs.floor();
@@ -74,7 +95,7 @@ impl<T: DecimalInt, const SCALE: u32> Decimal<T, SCALE> {
     }
 }
 
-fn round_quotient(
+pub(crate) const fn round_quotient(
     minor_units: i128,
     factor: i128,
     mode: RoundingMode,
@@ -87,8 +108,11 @@ fn round_quotient(
 
     let abs_rem = rem.abs();
     let abs_factor = factor.abs();
+    let negative = minor_units.is_negative() != factor.is_negative();
     let should_round = match mode {
         RoundingMode::Truncate => false,
+        RoundingMode::Floor => negative,
+        RoundingMode::Ceiling => !negative,
         RoundingMode::HalfUp => abs_rem * 2 >= abs_factor,
         RoundingMode::HalfEven => {
             let twice = abs_rem * 2;
@@ -106,14 +130,54 @@ fn round_quotient(
         return Ok(base);
     }
 
-    let adjusted = if minor_units.is_negative() {
+    let adjusted = if negative {
         base.checked_sub(1)
     } else {
         base.checked_add(1)
+    };
+
+    match adjusted {
+        Some(adjusted) => Ok(adjusted),
+        None => Err(DecimalError::Overflow),
     }
-    .ok_or(DecimalError::Overflow)?;
+}
 
-    Ok(adjusted)
+/// Round `sqrt(numerator / denominator)` to an integer with the given mode.
+///
+/// The root is non-negative, so `Floor` truncates and `Ceiling` rounds up.
+pub(crate) fn round_sqrt_ratio(
+    numerator: &BigUint,
+    denominator: &BigUint,
+    mode: RoundingMode,
+) -> Result<BigUint, DecimalError> {
+    if denominator.is_zero() {
+        return Err(DecimalError::DivisionByZero);
+    }
+
+    let base = numerator.div_rem(denominator).0.nth_root(2);
+    let one = BigUint::from_u128(1);
+    let should_round = match mode {
+        RoundingMode::Truncate | RoundingMode::Floor => false,
+        RoundingMode::Ceiling => base.mul(&base).mul(denominator) != *numerator,
+        RoundingMode::HalfUp | RoundingMode::HalfEven => {
+            // Compare numerator / denominator against (base + 1/2)^2 without fractions.
+            let mut lhs = numerator.clone();
+            lhs.mul_small(4);
+            let mut midpoint = base.mul(&base).add(&base);
+            midpoint.mul_small(4);
+            let midpoint = midpoint.add(&one).mul(denominator);
+            match mode {
+                RoundingMode::HalfUp => lhs >= midpoint,
+                _ => lhs > midpoint || (lhs == midpoint && base.is_odd()),
+            }
+        }
+    };
+
+    if should_round {
+        Ok(base.add(&one))
+    } else {
+        Ok(base)
+    }
 }
 
 impl<T: DecimalInt, const FROM: u32> Decimal<T, FROM> {
@@ -1,17 +1,42 @@
 use crate::decimal::{Decimal, DecimalInt};
 
 impl<T: DecimalInt, const SCALE: u32> Decimal<T, SCALE> {
+    /// Smallest representable value.
+    pub const MIN: Self = Self {
+        minor_units: T::MIN,
+    };
+
+    /// Largest representable value.
+    pub const MAX: Self = Self {
+        minor_units: T::MAX,
+    };
+
+    /// Smallest positive value (one minor unit, `10^-SCALE`).
+    pub const EPSILON: Self = Self {
+        minor_units: T::ONE,
+    };
+
     /// Zero value for the given scale.
-    pub fn zero() -> Self {
+    pub const fn zero() -> Self {
         Self {
-            minor_units: T::try_from_i128(0).unwrap_or_else(|| unreachable!()),
+            minor_units: T::ZERO,
         }
     }
 
     /// One value for the given scale (`1.0`).
-    pub fn one() -> Self {
-        Self::from_i128(10_i128.pow(SCALE)).unwrap_or_else(|_| {
-            unreachable!("Decimal one() overflowed for SCALE {}", SCALE)
-        })
+    pub const fn one() -> Self {
+        Self {
+            minor_units: T::POWERS_OF_TEN[SCALE as usize],
+        }
+    }
+
+    /// Construct directly from a scaled integer (`value / 10^SCALE`).
+    pub const fn from_minor_units(minor_units: T) -> Self {
+        Self { minor_units }
+    }
+
+    /// Scaled integer backing this value.
+    pub const fn minor_units(self) -> T {
+        self.minor_units
     }
 }
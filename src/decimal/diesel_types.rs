@@ -0,0 +1,23 @@
+use std::fmt;
+use std::io::Write;
+
+use diesel::deserialize::{self, FromSql};
+use diesel::pg::{Pg, PgValue};
+use diesel::serialize::{self, IsNull, Output, ToSql};
+use diesel::sql_types::Numeric;
+
+use crate::decimal::{Decimal, DecimalInt};
+
+/// Reads `Numeric` through `from_pg_numeric`, so extra decimals are an error.
+impl<T: DecimalInt, const SCALE: u32> FromSql<Numeric, Pg> for Decimal<T, SCALE> {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+        Ok(Self::from_pg_numeric(value.as_bytes())?)
+    }
+}
+
+impl<T: DecimalInt + fmt::Debug, const SCALE: u32> ToSql<Numeric, Pg> for Decimal<T, SCALE> {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(&self.to_pg_numeric())?;
+        Ok(IsNull::No)
+    }
+}
@@ -0,0 +1,212 @@
+use crate::decimal::{Decimal, DecimalError, DecimalInt, MAX_SCALE};
+
+/// Longest varint needed for a `u128`: `ceil(128 / 7)` bytes.
+const MAX_VARINT_LEN: usize = 19;
+
+/// Length of the scale-independent order-preserving encoding.
+pub const NORMALIZED_KEY_LEN: usize = 24;
+
+/// Sign bit of the most significant 64-bit limb.
+const SIGN_BIT: u64 = 1 << 63;
+
+impl<T: DecimalInt, const SCALE: u32> Decimal<T, SCALE> {
+    /// Minor units as little-endian two's complement bytes.
+    ///
+    /// The scale is not stored; it is fixed by the type on both ends.
+    pub fn to_le_bytes(self) -> T::Bytes {
+        self.minor_units.to_le_bytes()
+    }
+
+    /// Minor units as big-endian two's complement bytes.
+    pub fn to_be_bytes(self) -> T::Bytes {
+        self.minor_units.to_be_bytes()
+    }
+
+    /// Inverse of `to_le_bytes`.
+    pub fn from_le_bytes(bytes: T::Bytes) -> Self {
+        Decimal {
+            minor_units: T::from_le_bytes(bytes),
+        }
+    }
+
+    /// Inverse of `to_be_bytes`.
+    pub fn from_be_bytes(bytes: T::Bytes) -> Self {
+        Decimal {
+            minor_units: T::from_be_bytes(bytes),
+        }
+    }
+
+    /// Append the compact encoding: one scale byte, then the minor units as
+    /// a zigzag LEB128 varint.
+    ///
+    /// Small magnitudes take few bytes whatever the backing type, so `0.00`
+    /// is two bytes and `-1.50` three.
+    pub fn encode_compact(self, out: &mut Vec<u8>) {
+        out.push(SCALE as u8);
+        let minor = self.minor_units.to_i128();
+        let mut zigzag = ((minor << 1) ^ (minor >> 127)) as u128;
+        while zigzag >= 0x80 {
+            out.push((zigzag as u8 & 0x7f) | 0x80);
+            zigzag >>= 7;
+        }
+        out.push(zigzag as u8);
+    }
+
+    /// The compact encoding as a new buffer.
+    pub fn to_compact_bytes(self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.encode_compact(&mut out);
+        out
+    }
+
+    /// Decode one compactly encoded value from the start of `input`,
+    /// returning it with the number of bytes read.
+    ///
+    /// Returns `ScaleMismatch` if the encoded scale is not `SCALE`,
+    /// `Truncated` if the input ends early, `Invalid` for a varint longer
+    /// than any `i128` or not in its shortest form, and `Overflow` if the
+    /// value does not fit `T`.
+    pub fn decode_compact(input: &[u8]) -> Result<(Self, usize), DecimalError> {
+        let (&scale, rest) = input.split_first().ok_or(DecimalError::Truncated)?;
+        if u32::from(scale) != SCALE {
+            return Err(DecimalError::ScaleMismatch {
+                expected: SCALE,
+                found: u32::from(scale),
+            });
+        }
+
+        let mut zigzag = 0_u128;
+        for (index, &byte) in rest.iter().enumerate() {
+            let shift = 7 * index as u32;
+            let bits = u128::from(byte & 0x7f);
+            // The last group holds only the top two bits of a u128.
+            if index == MAX_VARINT_LEN || (index == MAX_VARINT_LEN - 1 && bits > 0x03) {
+                return Err(DecimalError::Invalid);
+            }
+            zigzag |= bits << shift;
+            if byte & 0x80 == 0 {
+                // A trailing zero group only pads the varint.
+                if index > 0 && byte == 0 {
+                    return Err(DecimalError::Invalid);
+                }
+                let minor = (zigzag >> 1) as i128 ^ -((zigzag & 1) as i128);
+                return Ok((Self::from_i128(minor)?, index + 2));
+            }
+        }
+        Err(DecimalError::Truncated)
+    }
+
+    /// Decode a buffer holding exactly one compactly encoded value.
+    ///
+    /// Trailing bytes are `Invalid`.
+    pub fn from_compact_bytes(input: &[u8]) -> Result<Self, DecimalError> {
+        let (value, read) = Self::decode_compact(input)?;
+        if read != input.len() {
+            return Err(DecimalError::Invalid);
+        }
+        Ok(value)
+    }
+
+    /// Order-preserving key: big-endian bytes with the sign bit flipped, so
+    /// comparing keys bytewise matches `Ord` for values of this type.
+    pub fn to_memcomparable(self) -> T::Bytes {
+        let mut bytes = self.minor_units.to_be_bytes();
+        bytes.as_mut()[0] ^= 0x80;
+        bytes
+    }
+
+    /// Inverse of `to_memcomparable`.
+    pub fn from_memcomparable(mut bytes: T::Bytes) -> Self {
+        bytes.as_mut()[0] ^= 0x80;
+        Self::from_be_bytes(bytes)
+    }
+
+    /// Order-preserving key that is the same for equal values at any scale
+    /// and on either backing, so keys from different types sort together.
+    ///
+    /// The value is widened to the largest supported scale and stored as a
+    /// 192-bit big-endian integer with the sign bit flipped.
+    pub fn to_memcomparable_normalized(self) -> [u8; NORMALIZED_KEY_LEN] {
+        let minor = self.minor_units.to_i128();
+        let factor = u128::from(10_u64.pow(MAX_SCALE - SCALE));
+        let magnitude = minor.unsigned_abs();
+        let low = (magnitude & u128::from(u64::MAX)) * factor;
+        let high = (magnitude >> 64) * factor + (low >> 64);
+        let mut limbs = [(high >> 64) as u64, high as u64, low as u64];
+        if minor < 0 {
+            negate(&mut limbs);
+        }
+        limbs[0] ^= SIGN_BIT;
+
+        let mut key = [0; NORMALIZED_KEY_LEN];
+        for (chunk, limb) in key.chunks_exact_mut(8).zip(limbs) {
+            chunk.copy_from_slice(&limb.to_be_bytes());
+        }
+        key
+    }
+
+    /// Inverse of `to_memcomparable_normalized`.
+    ///
+    /// Returns `TooManyFractionalDigits` if the value needs more than `SCALE`
+    /// decimals and `Overflow` if it does not fit `T`.
+    pub fn from_memcomparable_normalized(
+        key: &[u8; NORMALIZED_KEY_LEN],
+    ) -> Result<Self, DecimalError> {
+        let mut limbs = [0_u64; 3];
+        for (limb, chunk) in limbs.iter_mut().zip(key.chunks_exact(8)) {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(chunk);
+            *limb = u64::from_be_bytes(bytes);
+        }
+        limbs[0] ^= SIGN_BIT;
+        let negative = limbs[0] & SIGN_BIT != 0;
+        if negative {
+            negate(&mut limbs);
+        }
+
+        let (quotient, remainder) = div_rem(limbs, 10_u64.pow(MAX_SCALE - SCALE));
+        if remainder != 0 {
+            let trailing_zeros = (1..MAX_SCALE)
+                .take_while(|&digits| div_rem(limbs, 10_u64.pow(digits)).1 == 0)
+                .count();
+            return Err(DecimalError::TooManyFractionalDigits {
+                provided: MAX_SCALE as usize - trailing_zeros,
+                allowed: SCALE as usize,
+            });
+        }
+        if quotient[0] != 0 {
+            return Err(DecimalError::Overflow);
+        }
+
+        let magnitude = (u128::from(quotient[1]) << 64) | u128::from(quotient[2]);
+        let minor = if negative {
+            0_i128.checked_sub_unsigned(magnitude)
+        } else {
+            i128::try_from(magnitude).ok()
+        };
+        Self::from_i128(minor.ok_or(DecimalError::Overflow)?)
+    }
+}
+
+/// Two's complement negation of a big-endian 192-bit integer.
+fn negate(limbs: &mut [u64; 3]) {
+    let mut carry = true;
+    for limb in limbs.iter_mut().rev() {
+        let (value, overflow) = (!*limb).overflowing_add(u64::from(carry));
+        *limb = value;
+        carry = overflow;
+    }
+}
+
+/// Divide a big-endian 192-bit unsigned integer by `divisor`.
+fn div_rem(limbs: [u64; 3], divisor: u64) -> ([u64; 3], u64) {
+    let divisor = u128::from(divisor);
+    let mut quotient = [0; 3];
+    let mut remainder = 0_u128;
+    for (digit, limb) in quotient.iter_mut().zip(limbs) {
+        let current = (remainder << 64) | u128::from(limb);
+        *digit = (current / divisor) as u64;
+        remainder = current % divisor;
+    }
+    (quotient, remainder as u64)
+}
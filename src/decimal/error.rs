@@ -8,6 +8,15 @@ pub enum DecimalError {
     TooManyFractionalDigits { provided: usize, allowed: usize },
     DivisionByZero,
     Overflow,
+    Domain,
+    NoSolution,
+    CurrencyMismatch,
+    InvalidIncrement,
+    ScaleMismatch { expected: u32, found: u32 },
+    Truncated,
+    NonFinite,
+    TooManyDigits { provided: usize, allowed: usize },
+    MalformedDigit { offset: usize },
 }
 
 impl fmt::Display for DecimalError {
@@ -24,6 +33,27 @@ impl fmt::Display for DecimalError {
             }
             DecimalError::DivisionByZero => write!(f, "division by zero"),
             DecimalError::Overflow => write!(f, "value out of range"),
+            DecimalError::Domain => write!(f, "argument outside the function's domain"),
+            DecimalError::NoSolution => write!(f, "no solution in the searched range"),
+            DecimalError::CurrencyMismatch => write!(f, "currencies do not match"),
+            DecimalError::InvalidIncrement => {
+                write!(f, "value is not a multiple of the required increment")
+            }
+            DecimalError::ScaleMismatch { expected, found } => {
+                write!(f, "scale mismatch (expected {}, found {})", expected, found)
+            }
+            DecimalError::Truncated => write!(f, "input ended before the value was complete"),
+            DecimalError::NonFinite => write!(f, "value is NaN or infinite"),
+            DecimalError::TooManyDigits { provided, allowed } => {
+                write!(
+                    f,
+                    "too many digits (provided {}, allowed {})",
+                    provided, allowed
+                )
+            }
+            DecimalError::MalformedDigit { offset } => {
+                write!(f, "malformed digit or sign at byte {}", offset)
+            }
         }
     }
 }
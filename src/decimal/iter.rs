@@ -0,0 +1,70 @@
+use std::iter::Sum;
+
+use crate::decimal::{
+    BigInt, BigUint, Decimal, DecimalError, DecimalInt, RoundingMode, round_ratio, signed_result,
+};
+
+impl<T: DecimalInt, const SCALE: u32> Decimal<T, SCALE> {
+    /// Sum an iterator, accumulating in `i128` and checking the final value.
+    ///
+    /// Intermediate totals may exceed `T` as long as the result fits.
+    pub fn checked_sum<I>(iter: I) -> Result<Self, DecimalError>
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        let total = iter.into_iter().try_fold(0_i128, |acc, value| {
+            acc.checked_add(value.minor_units.to_i128())
+                .ok_or(DecimalError::Overflow)
+        })?;
+        Self::from_i128(total)
+    }
+
+    /// Multiply an iterator of values at this scale, rounding once at the end.
+    ///
+    /// The running product is exact, so only the final result has to fit;
+    /// an empty iterator yields `one()`.
+    pub fn product<I>(iter: I, mode: RoundingMode) -> Result<Self, DecimalError>
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        let mut total = BigInt::from_i128(1);
+        let mut factors = 0_u32;
+        for value in iter {
+            let value = value.minor_units.to_i128();
+            if value == 0 {
+                return Ok(Self::zero());
+            }
+            total = total.mul(&BigInt::from_i128(value));
+            factors = factors.checked_add(1).ok_or(DecimalError::Overflow)?;
+        }
+        if factors == 0 {
+            return Ok(Self::one());
+        }
+
+        // Each factor after the first carries an extra `10^SCALE`.
+        let exponent = SCALE
+            .checked_mul(factors - 1)
+            .ok_or(DecimalError::Overflow)?;
+        let negative = total.is_negative();
+        let magnitude = round_ratio(
+            total.magnitude(),
+            &BigUint::pow10(exponent),
+            mode.for_magnitude(negative),
+        );
+        signed_result(magnitude, negative)
+    }
+}
+
+/// Sum values; panics if the total does not fit the backing type.
+impl<T: DecimalInt, const SCALE: u32> Sum for Decimal<T, SCALE> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self::checked_sum(iter).expect("decimal sum overflowed")
+    }
+}
+
+/// Sum borrowed values; panics if the total does not fit the backing type.
+impl<'a, T: DecimalInt, const SCALE: u32> Sum<&'a Decimal<T, SCALE>> for Decimal<T, SCALE> {
+    fn sum<I: Iterator<Item = &'a Decimal<T, SCALE>>>(iter: I) -> Self {
+        Self::checked_sum(iter.copied()).expect("decimal sum overflowed")
+    }
+}
@@ -6,8 +6,32 @@ mod arithmetic;
 mod display;
 mod parsing;
 mod defaults;
+mod const_ops;
+mod scale;
+mod iter;
+mod wide;
+mod accumulator;
+mod allocation;
+mod big;
+mod powers;
+mod transcendental;
+mod percent;
+mod quantize;
+mod encoding;
+mod pg_numeric;
+#[cfg(feature = "postgres")]
+mod pg_types;
+#[cfg(feature = "sqlx")]
+mod sqlx_types;
+#[cfg(feature = "diesel")]
+mod diesel_types;
+#[cfg(feature = "arrow")]
+mod arrow_types;
+#[cfg(feature = "bytemuck")]
+mod bytemuck_types;
+#[cfg(feature = "rkyv")]
+mod rkyv_types;
 
-#[allow(dead_code)]
 const MAX_SCALE: u32 = 18;
 
 /// Backing integer behavior required by `Decimal`.
@@ -20,13 +44,51 @@ pub trait DecimalInt:
     + std::ops::AddAssign
     + std::ops::SubAssign
 {
+    /// Additive identity.
+    const ZERO: Self;
+    /// Smallest representable value.
+    const MIN: Self;
+    /// Largest representable value.
+    const MAX: Self;
+    /// Smallest positive value (one minor unit).
+    const ONE: Self;
+    /// `10^n` for every supported scale `n`.
+    const POWERS_OF_TEN: [Self; MAX_SCALE as usize + 1];
+
+    /// Fixed-width two's complement bytes, e.g. `[u8; 8]` for `i64`.
+    type Bytes: Copy + AsRef<[u8]> + AsMut<[u8]> + Default;
+
     fn checked_add(self, rhs: Self) -> Option<Self>;
     fn checked_sub(self, rhs: Self) -> Option<Self>;
     fn to_i128(self) -> i128;
     fn try_from_i128(value: i128) -> Option<Self>;
+    fn to_le_bytes(self) -> Self::Bytes;
+    fn to_be_bytes(self) -> Self::Bytes;
+    fn from_le_bytes(bytes: Self::Bytes) -> Self;
+    fn from_be_bytes(bytes: Self::Bytes) -> Self;
+}
+
+macro_rules! powers_of_ten {
+    ($t:ty) => {{
+        let mut table = [1 as $t; MAX_SCALE as usize + 1];
+        let mut i = 1;
+        while i < table.len() {
+            table[i] = table[i - 1] * 10;
+            i += 1;
+        }
+        table
+    }};
 }
 
 impl DecimalInt for i64 {
+    const ZERO: Self = 0;
+    const MIN: Self = i64::MIN;
+    const MAX: Self = i64::MAX;
+    const ONE: Self = 1;
+    const POWERS_OF_TEN: [Self; MAX_SCALE as usize + 1] = powers_of_ten!(i64);
+
+    type Bytes = [u8; 8];
+
     fn checked_add(self, rhs: Self) -> Option<Self> {
         i64::checked_add(self, rhs)
     }
@@ -42,9 +104,33 @@ impl DecimalInt for i64 {
     fn try_from_i128(value: i128) -> Option<Self> {
         i64::try_from(value).ok()
     }
+
+    fn to_le_bytes(self) -> Self::Bytes {
+        i64::to_le_bytes(self)
+    }
+
+    fn to_be_bytes(self) -> Self::Bytes {
+        i64::to_be_bytes(self)
+    }
+
+    fn from_le_bytes(bytes: Self::Bytes) -> Self {
+        i64::from_le_bytes(bytes)
+    }
+
+    fn from_be_bytes(bytes: Self::Bytes) -> Self {
+        i64::from_be_bytes(bytes)
+    }
 }
 
 impl DecimalInt for i128 {
+    const ZERO: Self = 0;
+    const MIN: Self = i128::MIN;
+    const MAX: Self = i128::MAX;
+    const ONE: Self = 1;
+    const POWERS_OF_TEN: [Self; MAX_SCALE as usize + 1] = powers_of_ten!(i128);
+
+    type Bytes = [u8; 16];
+
     fn checked_add(self, rhs: Self) -> Option<Self> {
         i128::checked_add(self, rhs)
     }
@@ -60,12 +146,39 @@ impl DecimalInt for i128 {
     fn try_from_i128(value: i128) -> Option<Self> {
         Some(value)
     }
+
+    fn to_le_bytes(self) -> Self::Bytes {
+        i128::to_le_bytes(self)
+    }
+
+    fn to_be_bytes(self) -> Self::Bytes {
+        i128::to_be_bytes(self)
+    }
+
+    fn from_le_bytes(bytes: Self::Bytes) -> Self {
+        i128::from_le_bytes(bytes)
+    }
+
+    fn from_be_bytes(bytes: Self::Bytes) -> Self {
+        i128::from_be_bytes(bytes)
+    }
 }
 
 /// Fixed-scale decimal with backing integer `T`.
 ///
-/// The stored integer represents `value / 10^SCALE`.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// The stored integer represents `value / 10^SCALE`. The layout is that of
+/// `T`, so a `&[T]` of minor units can be viewed as a `&[Decimal<T, SCALE>]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Numeric)
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 pub struct Decimal<T, const SCALE: u32> {
     minor_units: T,
 }
@@ -93,3 +206,20 @@ impl<T: DecimalInt, const SCALE: u32> Decimal<T, SCALE> {
 pub use self::error::DecimalError;
 /// Rounding modes for scale-changing operations.
 pub use self::conversion::RoundingMode;
+/// Remainder distribution for proportional splits.
+pub use self::allocation::AllocationStrategy;
+/// Wide running totals for large aggregations.
+pub use self::accumulator::DecimalAccumulator;
+/// Type-level scale arithmetic for exact products.
+pub use self::scale::ScaleSum;
+/// Rates as percentages and basis points.
+pub use self::percent::{BasisPoints, Percent};
+/// Length of scale-independent sort keys.
+pub use self::encoding::NORMALIZED_KEY_LEN;
+/// Lenient parsing grammar.
+pub use self::parsing::ParseOptions;
+
+pub(crate) use self::big::{BigInt, BigUint};
+pub(crate) use self::conversion::{round_quotient, round_sqrt_ratio};
+pub(crate) use self::powers::{div_ceil, power_bounds, refine, round_ratio, signed_result};
+pub(crate) use self::transcendental::{ln_bounds, narrow};
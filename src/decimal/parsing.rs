@@ -1,76 +1,188 @@
-use std::num::IntErrorKind;
 use std::str::FromStr;
 
-use crate::decimal::{Decimal, DecimalError, DecimalInt};
+use crate::decimal::{Decimal, DecimalError, DecimalInt, RoundingMode};
 
 /// Parse a decimal string into a fixed-scale value.
+///
+/// Uses the grammar of `Decimal::parse_with` with default `ParseOptions`.
 impl<T: DecimalInt, const SCALE: u32> FromStr for Decimal<T, SCALE> {
     type Err = DecimalError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.is_empty() {
-            return Err(DecimalError::Empty);
-        }
+        Self::parse_with(s.as_bytes(), ParseOptions::default())
+    }
+}
 
-        let (negative, unsigned) = if let Some(rest) = s.strip_prefix('-') {
-            (true, rest)
-        } else if let Some(rest) = s.strip_prefix('+') {
-            (false, rest)
-        } else {
-            (false, s)
-        };
+/// Lenient grammar for `Decimal::parse_with`, e.g. for statement files.
+///
+/// The default is the grammar `FromStr` uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// Separator between integer and fractional digits.
+    pub decimal_separator: u8,
+    /// Separator allowed between integer digits, such as `b','` in `1,234`.
+    pub group_separator: Option<u8>,
+    /// Ignore leading and trailing ASCII whitespace.
+    pub trim_whitespace: bool,
+    /// Read `(1.50)` as `-1.50`, as accounting reports write negatives.
+    pub parentheses_negative: bool,
+    /// Round digits beyond `SCALE` instead of rejecting them.
+    pub rounding: Option<RoundingMode>,
+}
 
-        let (int_part, frac_part) = match unsigned.split_once('.') {
-            Some((int_part, frac_part)) => (int_part, frac_part),
-            None => (unsigned, ""),
-        };
+impl Default for ParseOptions {
+    fn default() -> Self {
+        STRICT
+    }
+}
 
-        if int_part.is_empty() && frac_part.is_empty() {
-            return Err(DecimalError::Invalid);
-        }
+/// `ParseOptions::default()`, usable in const contexts.
+pub(crate) const STRICT: ParseOptions = ParseOptions {
+    decimal_separator: b'.',
+    group_separator: None,
+    trim_whitespace: false,
+    parentheses_negative: false,
+    rounding: None,
+};
+
+impl<T: DecimalInt, const SCALE: u32> Decimal<T, SCALE> {
+    /// Parse ASCII bytes with the grammar set by `options`, without
+    /// allocating.
+    ///
+    /// Returns `Empty` for no input, `Invalid` for characters the options do
+    /// not allow, `TooManyFractionalDigits` for extra decimals unless
+    /// `options.rounding` is set, and `Overflow` if the value does not fit.
+    pub fn parse_with(input: &[u8], options: ParseOptions) -> Result<Self, DecimalError> {
+        Self::from_i128(parse_minor_units(input, SCALE, options)?)
+    }
+}
+
+/// The scaled integer for `parse_with`, as a `const fn` so the const parsers
+/// share the grammar.
+pub(crate) const fn parse_minor_units(
+    input: &[u8],
+    scale: u32,
+    options: ParseOptions,
+) -> Result<i128, DecimalError> {
+    let mut input = trim(input, options);
+    if input.is_empty() {
+        return Err(DecimalError::Empty);
+    }
 
-        if int_part.starts_with(['+', '-']) || frac_part.starts_with(['+', '-']) {
+    let mut parenthesized = false;
+    if options.parentheses_negative
+        && let [b'(', inner @ .., b')'] = input
+    {
+        parenthesized = true;
+        input = trim(inner, options);
+    }
+    let (negative, unsigned) = match input {
+        [b'-', rest @ ..] if !parenthesized => (true, rest),
+        [b'+', rest @ ..] if !parenthesized => (false, rest),
+        _ => (parenthesized, input),
+    };
+
+    let scale = scale as usize;
+    let mut magnitude = 0_u128;
+    let mut digits = 0;
+    // Fractional digits seen, once past the decimal separator.
+    let mut fraction: Option<usize> = None;
+    // First digit beyond `SCALE`, and whether any later one is non-zero.
+    let mut first_extra = 0;
+    let mut sticky = false;
+    let mut index = 0;
+    while index < unsigned.len() {
+        let byte = unsigned[index];
+        if byte.is_ascii_digit() {
+            let digit = byte - b'0';
+            digits += 1;
+            match fraction {
+                Some(count) if count >= scale => {
+                    if count == scale {
+                        first_extra = digit;
+                    } else {
+                        sticky |= digit != 0;
+                    }
+                }
+                _ => {
+                    magnitude = match magnitude.checked_mul(10) {
+                        Some(value) if value <= u128::MAX - digit as u128 => value + digit as u128,
+                        _ => return Err(DecimalError::Overflow),
+                    };
+                }
+            }
+            if let Some(count) = fraction {
+                fraction = Some(count + 1);
+            }
+        } else if byte == options.decimal_separator && fraction.is_none() {
+            fraction = Some(0);
+        } else if let Some(separator) = options.group_separator
+            && byte == separator
+            && fraction.is_none()
+            && index > 0
+            && unsigned[index - 1].is_ascii_digit()
+            && index + 1 < unsigned.len()
+            && unsigned[index + 1].is_ascii_digit()
+        {
+            // Group separators between integer digits are skipped.
+        } else {
             return Err(DecimalError::Invalid);
         }
+        index += 1;
+    }
+    if digits == 0 {
+        return Err(DecimalError::Invalid);
+    }
 
-        let decimals = SCALE as usize;
-        if frac_part.len() > decimals {
+    let fraction = match fraction {
+        Some(count) => count,
+        None => 0,
+    };
+    if fraction > scale {
+        let Some(mode) = options.rounding else {
             return Err(DecimalError::TooManyFractionalDigits {
-                provided: frac_part.len(),
-                allowed: decimals,
+                provided: fraction,
+                allowed: scale,
             });
+        };
+        let round_up = match mode.for_magnitude(negative) {
+            RoundingMode::Truncate | RoundingMode::Floor => false,
+            RoundingMode::Ceiling => first_extra > 0 || sticky,
+            RoundingMode::HalfUp => first_extra >= 5,
+            RoundingMode::HalfEven => {
+                first_extra > 5 || (first_extra == 5 && (sticky || !magnitude.is_multiple_of(2)))
+            }
+        };
+        if round_up {
+            magnitude = match magnitude.checked_add(1) {
+                Some(value) => value,
+                None => return Err(DecimalError::Overflow),
+            };
         }
-
-        let parse_i128 = |input: &str| {
-            input.parse::<i128>().map_err(|err| match err.kind() {
-                IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => DecimalError::Overflow,
-                _ => DecimalError::Invalid,
-            })
+    } else {
+        magnitude = match magnitude.checked_mul(10_u128.pow((scale - fraction) as u32)) {
+            Some(value) => value,
+            None => return Err(DecimalError::Overflow),
         };
+    }
 
-        let int_val = if int_part.is_empty() { 0 } else { parse_i128(int_part)? };
-        let frac_val = if frac_part.is_empty() { 0 } else { parse_i128(frac_part)? };
-
-        let int_scale = 10_i128.pow(SCALE);
-        let frac_scale = 10_i128.pow(SCALE - (frac_part.len() as u32));
-
-        let scaled_int_part = int_val
-            .checked_mul(int_scale)
-            .ok_or(DecimalError::Overflow)?;
-
-        let scaled_frac_part = frac_val
-            .checked_mul(frac_scale)
-            .ok_or(DecimalError::Overflow)?;
-
-        let minor = scaled_int_part
-            .checked_add(scaled_frac_part)
-            .ok_or(DecimalError::Overflow)?;
+    let minor = if negative {
+        0_i128.checked_sub_unsigned(magnitude)
+    } else if magnitude <= i128::MAX as u128 {
+        Some(magnitude as i128)
+    } else {
+        None
+    };
+    match minor {
+        Some(minor) => Ok(minor),
+        None => Err(DecimalError::Overflow),
+    }
+}
 
-        let signed = if negative {
-            minor.checked_neg().ok_or(DecimalError::Overflow)?
-        } else {
-            minor
-        };
-        Self::from_i128(signed)
+const fn trim(input: &[u8], options: ParseOptions) -> &[u8] {
+    if options.trim_whitespace {
+        input.trim_ascii()
+    } else {
+        input
     }
 }
@@ -0,0 +1,200 @@
+use std::fmt;
+
+use crate::decimal::{Decimal, DecimalError, DecimalInt, RoundingMode, round_quotient};
+
+/// A percentage: a value of `5` means 5%, the fraction `0.05`.
+///
+/// Keeping percentages apart from plain decimals means a rate can only reach
+/// an amount through `apply_to` or an explicit conversion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Percent<T, const SCALE: u32>(Decimal<T, SCALE>);
+
+/// A whole number of basis points: a value of `25` means 0.25%.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BasisPoints<T>(Decimal<T, 0>);
+
+impl<T: DecimalInt, const SCALE: u32> Percent<T, SCALE> {
+    /// Wrap a value expressed in percent.
+    pub const fn new(percent: Decimal<T, SCALE>) -> Self {
+        Percent(percent)
+    }
+
+    /// The value in percent.
+    pub const fn value(self) -> Decimal<T, SCALE> {
+        self.0
+    }
+
+    /// Convert a fraction (`0.05`) to a percentage (`5%`).
+    pub fn from_fraction<const FROM: u32>(
+        fraction: Decimal<T, FROM>,
+        mode: RoundingMode,
+    ) -> Result<Self, DecimalError> {
+        let minor = shift(
+            fraction.minor_units.to_i128(),
+            exponent(SCALE + 2, FROM),
+            mode,
+        )?;
+        Ok(Percent(Decimal::from_i128(minor)?))
+    }
+
+    /// The fraction this percentage represents, rounded to `OUT`.
+    pub fn to_fraction<const OUT: u32>(
+        self,
+        mode: RoundingMode,
+    ) -> Result<Decimal<T, OUT>, DecimalError> {
+        let minor = shift(self.0.minor_units.to_i128(), exponent(OUT, SCALE + 2), mode)?;
+        Decimal::from_i128(minor)
+    }
+
+    /// The same rate in whole basis points.
+    pub fn to_basis_points(self, mode: RoundingMode) -> Result<BasisPoints<T>, DecimalError> {
+        let minor = shift(self.0.minor_units.to_i128(), exponent(2, SCALE), mode)?;
+        Ok(BasisPoints(Decimal::from_i128(minor)?))
+    }
+
+    /// This percentage of `amount`, rounded to the amount's scale.
+    pub fn apply_to<const AMOUNT: u32>(
+        self,
+        amount: Decimal<T, AMOUNT>,
+        mode: RoundingMode,
+    ) -> Result<Decimal<T, AMOUNT>, DecimalError> {
+        apply(amount, self.0.minor_units.to_i128(), SCALE + 2, mode)
+    }
+
+    /// Relative change from `from` to `to`, i.e. `(to - from) / |from|`.
+    ///
+    /// Returns `DivisionByZero` if `from` is zero.
+    pub fn change<const AMOUNT: u32>(
+        from: Decimal<T, AMOUNT>,
+        to: Decimal<T, AMOUNT>,
+        mode: RoundingMode,
+    ) -> Result<Self, DecimalError> {
+        let from = from.minor_units.to_i128();
+        let delta = to
+            .minor_units
+            .to_i128()
+            .checked_sub(from)
+            .ok_or(DecimalError::Overflow)?;
+        let divisor = from.checked_abs().ok_or(DecimalError::Overflow)?;
+        Self::ratio(delta, divisor, mode)
+    }
+
+    /// Symmetric difference `|a - b|` relative to the mean of `a` and `b`.
+    ///
+    /// Returns `DivisionByZero` if `a + b` is zero.
+    pub fn difference<const AMOUNT: u32>(
+        a: Decimal<T, AMOUNT>,
+        b: Decimal<T, AMOUNT>,
+        mode: RoundingMode,
+    ) -> Result<Self, DecimalError> {
+        let (a, b) = (a.minor_units.to_i128(), b.minor_units.to_i128());
+        let spread = a.checked_sub(b).and_then(i128::checked_abs);
+        let twice_spread = spread
+            .and_then(|spread| spread.checked_mul(2))
+            .ok_or(DecimalError::Overflow)?;
+        let total = a
+            .checked_add(b)
+            .and_then(i128::checked_abs)
+            .ok_or(DecimalError::Overflow)?;
+        Self::ratio(twice_spread, total, mode)
+    }
+
+    /// `numerator / denominator` expressed in percent.
+    fn ratio(numerator: i128, denominator: i128, mode: RoundingMode) -> Result<Self, DecimalError> {
+        if denominator == 0 {
+            return Err(DecimalError::DivisionByZero);
+        }
+        let scaled = numerator
+            .checked_mul(pow10(SCALE + 2)?)
+            .ok_or(DecimalError::Overflow)?;
+        let minor = round_quotient(scaled, denominator, mode)?;
+        Ok(Percent(Decimal::from_i128(minor)?))
+    }
+}
+
+impl<T: DecimalInt> BasisPoints<T> {
+    /// Wrap a whole number of basis points.
+    pub const fn new(bps: T) -> Self {
+        BasisPoints(Decimal::from_minor_units(bps))
+    }
+
+    /// The number of basis points.
+    pub const fn value(self) -> T {
+        self.0.minor_units
+    }
+
+    /// The fraction these basis points represent (`25bp → 0.0025`), rounded to `OUT`.
+    pub fn to_fraction<const OUT: u32>(
+        self,
+        mode: RoundingMode,
+    ) -> Result<Decimal<T, OUT>, DecimalError> {
+        let minor = shift(self.0.minor_units.to_i128(), exponent(OUT, 4), mode)?;
+        Decimal::from_i128(minor)
+    }
+
+    /// The same rate in percent, rounded to `SCALE`.
+    pub fn to_percent<const SCALE: u32>(
+        self,
+        mode: RoundingMode,
+    ) -> Result<Percent<T, SCALE>, DecimalError> {
+        let minor = shift(self.0.minor_units.to_i128(), exponent(SCALE, 2), mode)?;
+        Ok(Percent(Decimal::from_i128(minor)?))
+    }
+
+    /// These basis points of `amount`, rounded to the amount's scale.
+    pub fn apply_to<const AMOUNT: u32>(
+        self,
+        amount: Decimal<T, AMOUNT>,
+        mode: RoundingMode,
+    ) -> Result<Decimal<T, AMOUNT>, DecimalError> {
+        apply(amount, self.0.minor_units.to_i128(), 4, mode)
+    }
+}
+
+/// Format with a `%` suffix, e.g. `5.25%`.
+impl<T: DecimalInt + fmt::Display, const SCALE: u32> fmt::Display for Percent<T, SCALE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}%", self.0)
+    }
+}
+
+/// Format with a `bp` suffix, e.g. `25bp`.
+impl<T: DecimalInt + fmt::Display> fmt::Display for BasisPoints<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}bp", self.0)
+    }
+}
+
+/// `amount * rate / 10^rate_scale`, rounded to the amount's scale.
+fn apply<T: DecimalInt, const AMOUNT: u32>(
+    amount: Decimal<T, AMOUNT>,
+    rate: i128,
+    rate_scale: u32,
+    mode: RoundingMode,
+) -> Result<Decimal<T, AMOUNT>, DecimalError> {
+    let product = amount
+        .minor_units
+        .to_i128()
+        .checked_mul(rate)
+        .ok_or(DecimalError::Overflow)?;
+    Decimal::from_i128(round_quotient(product, pow10(rate_scale)?, mode)?)
+}
+
+/// Signed power-of-ten exponent taking scale `from` to scale `to`.
+fn exponent(to: u32, from: u32) -> i64 {
+    i64::from(to) - i64::from(from)
+}
+
+/// `minor * 10^exponent`, rounding when the exponent is negative.
+fn shift(minor: i128, exponent: i64, mode: RoundingMode) -> Result<i128, DecimalError> {
+    let factor = pow10(exponent.unsigned_abs() as u32)?;
+    if exponent >= 0 {
+        minor.checked_mul(factor).ok_or(DecimalError::Overflow)
+    } else {
+        round_quotient(minor, factor, mode)
+    }
+}
+
+fn pow10(exp: u32) -> Result<i128, DecimalError> {
+    10_i128.checked_pow(exp).ok_or(DecimalError::Overflow)
+}
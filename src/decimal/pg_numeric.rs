@@ -0,0 +1,211 @@
+use crate::decimal::{
+    BigUint, Decimal, DecimalError, DecimalInt, RoundingMode, round_ratio, signed_result,
+};
+
+const POSITIVE: u16 = 0x0000;
+const NEGATIVE: u16 = 0x4000;
+const NAN: u16 = 0xC000;
+const POSITIVE_INFINITY: u16 = 0xD000;
+const NEGATIVE_INFINITY: u16 = 0xF000;
+
+/// Base of a NUMERIC digit, and the decimal digits it holds.
+const NBASE: u16 = 10_000;
+const DEC_DIGITS: usize = 4;
+
+/// Header: digit count, weight, sign and display scale as big-endian 16-bit words.
+const HEADER_LEN: usize = 8;
+
+impl<T: DecimalInt, const SCALE: u32> Decimal<T, SCALE> {
+    /// Encode in PostgreSQL's binary `NUMERIC` format with a display scale
+    /// of `SCALE`, as sent by `numeric_send`.
+    pub fn to_pg_numeric(self) -> Vec<u8> {
+        let minor = self.minor_units.to_i128();
+        let digits = minor.unsigned_abs().to_string();
+
+        // Left-pad so there is at least one integer digit, then align both
+        // sides of the decimal point to whole base-10000 digits.
+        let scale = SCALE as usize;
+        let integer_len = digits.len().saturating_sub(scale).max(1);
+        let lead = (DEC_DIGITS - integer_len % DEC_DIGITS) % DEC_DIGITS;
+        let trail = (DEC_DIGITS - scale % DEC_DIGITS) % DEC_DIGITS;
+        let padded = format!(
+            "{}{:0>width$}{}",
+            "0".repeat(lead),
+            digits,
+            "0".repeat(trail),
+            width = integer_len + scale
+        );
+
+        let mut groups: Vec<i16> = padded
+            .as_bytes()
+            .chunks(DEC_DIGITS)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .fold(0, |group, digit| group * 10 + i16::from(digit - b'0'))
+            })
+            .collect();
+        let mut weight = ((lead + integer_len) / DEC_DIGITS) as i16 - 1;
+
+        // Postgres strips zero digits at both ends; zero has no digits.
+        let leading_zeros = groups.iter().take_while(|&&group| group == 0).count();
+        groups.drain(..leading_zeros);
+        weight -= leading_zeros as i16;
+        while groups.last() == Some(&0) {
+            groups.pop();
+        }
+        if groups.is_empty() {
+            weight = 0;
+        }
+
+        let sign = if minor < 0 { NEGATIVE } else { POSITIVE };
+        let mut out = Vec::with_capacity(HEADER_LEN + 2 * groups.len());
+        out.extend_from_slice(&(groups.len() as i16).to_be_bytes());
+        out.extend_from_slice(&weight.to_be_bytes());
+        out.extend_from_slice(&sign.to_be_bytes());
+        out.extend_from_slice(&(SCALE as u16).to_be_bytes());
+        for group in groups {
+            out.extend_from_slice(&group.to_be_bytes());
+        }
+        out
+    }
+
+    /// Decode PostgreSQL's binary `NUMERIC` format exactly.
+    ///
+    /// Returns `NonFinite` for NaN and infinities, `TooManyFractionalDigits`
+    /// if the value needs more than `SCALE` decimals, `Overflow` if it does
+    /// not fit `T`, and `Truncated` or `Invalid` for malformed input.
+    pub fn from_pg_numeric(bytes: &[u8]) -> Result<Self, DecimalError> {
+        let numeric = PgNumeric::parse(bytes)?;
+        let shift = numeric.exponent + i64::from(SCALE);
+        if shift < 0 {
+            let fractional_digits = -numeric.exponent - numeric.trailing_zeros();
+            if fractional_digits > i64::from(SCALE) {
+                return Err(DecimalError::TooManyFractionalDigits {
+                    provided: fractional_digits as usize,
+                    allowed: SCALE as usize,
+                });
+            }
+        }
+        numeric.to_decimal(shift, RoundingMode::Truncate)
+    }
+
+    /// Decode PostgreSQL's text `NUMERIC` output exactly, accepting what
+    /// `from_pg_numeric` accepts in binary.
+    ///
+    /// Trailing fractional zeros are insignificant, so `1.5000` from a
+    /// `numeric(10, 4)` column fits two decimals. Returns `NonFinite` for
+    /// `NaN` and infinities, and otherwise the errors of `FromStr`.
+    pub fn from_pg_text(text: &str) -> Result<Self, DecimalError> {
+        if matches!(text, "NaN" | "Infinity" | "-Infinity") {
+            return Err(DecimalError::NonFinite);
+        }
+        match text.split_once('.') {
+            Some((_, fraction)) if fraction.len() > SCALE as usize => {
+                text.trim_end_matches('0').trim_end_matches('.').parse()
+            }
+            _ => text.parse(),
+        }
+    }
+
+    /// Decode PostgreSQL's binary `NUMERIC` format, rounding to `SCALE`.
+    ///
+    /// Errors as `from_pg_numeric`, except that extra decimals are rounded.
+    pub fn from_pg_numeric_rounded(bytes: &[u8], mode: RoundingMode) -> Result<Self, DecimalError> {
+        let numeric = PgNumeric::parse(bytes)?;
+        numeric.to_decimal(numeric.exponent + i64::from(SCALE), mode)
+    }
+}
+
+/// A finite NUMERIC: `digits · 10^exponent`, base-10000 digits most significant first.
+struct PgNumeric {
+    negative: bool,
+    digits: Vec<u16>,
+    exponent: i64,
+}
+
+impl PgNumeric {
+    fn parse(bytes: &[u8]) -> Result<Self, DecimalError> {
+        let word = |index: usize| -> Result<u16, DecimalError> {
+            let at = 2 * index;
+            match bytes.get(at..at + 2) {
+                Some(&[high, low]) => Ok(u16::from_be_bytes([high, low])),
+                _ => Err(DecimalError::Truncated),
+            }
+        };
+
+        let ndigits = word(0)? as i16;
+        let weight = word(1)? as i16;
+        let negative = match word(2)? {
+            POSITIVE => false,
+            NEGATIVE => true,
+            NAN | POSITIVE_INFINITY | NEGATIVE_INFINITY => return Err(DecimalError::NonFinite),
+            _ => return Err(DecimalError::Invalid),
+        };
+        word(3)?;
+
+        let ndigits = usize::try_from(ndigits).map_err(|_| DecimalError::Invalid)?;
+        let digits = (0..ndigits)
+            .map(|index| match word(4 + index)? {
+                digit if digit < NBASE => Ok(digit),
+                _ => Err(DecimalError::Invalid),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if bytes.len() > HEADER_LEN + 2 * ndigits {
+            return Err(DecimalError::Invalid);
+        }
+
+        let last_weight = i64::from(weight) - ndigits as i64 + 1;
+        Ok(PgNumeric {
+            negative,
+            digits,
+            exponent: DEC_DIGITS as i64 * last_weight,
+        })
+    }
+
+    /// Decimal zeros at the end of the digit string.
+    fn trailing_zeros(&self) -> i64 {
+        let zero_digits = self.digits.iter().rev().take_while(|&&digit| digit == 0);
+        let mut zeros = DEC_DIGITS as i64 * zero_digits.count() as i64;
+        if let Some(mut digit) = self.digits.iter().rev().copied().find(|&digit| digit != 0) {
+            while digit % 10 == 0 {
+                digit /= 10;
+                zeros += 1;
+            }
+        }
+        zeros
+    }
+
+    /// `digits · 10^shift` rounded to an integer and narrowed to `T`.
+    fn to_decimal<T: DecimalInt, const SCALE: u32>(
+        &self,
+        shift: i64,
+        mode: RoundingMode,
+    ) -> Result<Decimal<T, SCALE>, DecimalError> {
+        let mut value = BigUint::zero();
+        for &digit in &self.digits {
+            value.mul_small(u32::from(NBASE));
+            value = value.add(&BigUint::from_u128(u128::from(digit)));
+        }
+        if value.is_zero() {
+            return Ok(Decimal::from_minor_units(T::ZERO));
+        }
+
+        let magnitude = if shift >= 0 {
+            // Any non-zero value scaled past 10^39 exceeds every backing type.
+            let shift = u32::try_from(shift)
+                .ok()
+                .filter(|&shift| shift <= 39)
+                .ok_or(DecimalError::Overflow)?;
+            value.mul(&BigUint::pow10(shift))
+        } else {
+            let shift = u32::try_from(-shift).map_err(|_| DecimalError::Overflow)?;
+            round_ratio(
+                &value,
+                &BigUint::pow10(shift),
+                mode.for_magnitude(self.negative),
+            )
+        };
+        signed_result(magnitude, self.negative)
+    }
+}
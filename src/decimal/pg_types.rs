@@ -0,0 +1,30 @@
+use std::error::Error;
+use std::fmt;
+
+use bytes::BytesMut;
+use postgres_types::{FromSql, IsNull, ToSql, Type, accepts, to_sql_checked};
+
+use crate::decimal::{Decimal, DecimalInt};
+
+/// Reads `NUMERIC` through `from_pg_numeric`, so extra decimals are an error.
+impl<'a, T: DecimalInt, const SCALE: u32> FromSql<'a> for Decimal<T, SCALE> {
+    fn from_sql(_ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        Ok(Self::from_pg_numeric(raw)?)
+    }
+
+    accepts!(NUMERIC);
+}
+
+impl<T: DecimalInt + fmt::Debug, const SCALE: u32> ToSql for Decimal<T, SCALE> {
+    fn to_sql(
+        &self,
+        _ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        out.extend_from_slice(&self.to_pg_numeric());
+        Ok(IsNull::No)
+    }
+
+    accepts!(NUMERIC);
+    to_sql_checked!();
+}
@@ -0,0 +1,304 @@
+use crate::decimal::big::BigUint;
+use crate::decimal::transcendental::{log_ratio, root_bounds};
+use crate::decimal::{Decimal, DecimalError, DecimalInt, RoundingMode};
+
+/// Extra fractional digits carried beyond the output scale on the first attempt.
+const GUARD_DIGITS: u32 = 8;
+
+/// Working precision at which bounds that still straddle a rounding boundary
+/// are taken to mean the exact result lies on it.
+const MAX_PRECISION: u32 = 256;
+
+/// Smallest root degree taken as `e^(ln(x) / n)`; no decimal other than 0 and
+/// 1 has an exact root this high, and the radicand would have `n * precision`
+/// digits.
+const LOG_ROOT_DEGREE: u32 = 128;
+
+/// Whole digits beyond which no value fits a 128-bit backing at any scale.
+const MAX_WHOLE_DIGITS: u64 = 39;
+
+impl<T: DecimalInt, const SCALE: u32> Decimal<T, SCALE> {
+    /// Raise to a non-negative integer power, correctly rounded to `OUT`.
+    pub fn pow<const OUT: u32>(
+        self,
+        exp: u32,
+        mode: RoundingMode,
+    ) -> Result<Decimal<T, OUT>, DecimalError> {
+        let minor = self.minor_units.to_i128();
+        let negative = minor < 0 && exp % 2 == 1;
+        let mode = mode.for_magnitude(negative);
+        let magnitude = refine(SCALE.max(OUT), OUT, mode, |precision| {
+            pow_bounds(minor.unsigned_abs(), SCALE, exp, precision).map(Some)
+        })?;
+        signed_result(magnitude, negative)
+    }
+
+    /// Raise to a signed integer power, correctly rounded to `OUT`.
+    ///
+    /// Negative exponents take the reciprocal; zero to a negative power is a
+    /// `DivisionByZero`.
+    pub fn powi<const OUT: u32>(
+        self,
+        exp: i32,
+        mode: RoundingMode,
+    ) -> Result<Decimal<T, OUT>, DecimalError> {
+        if exp >= 0 {
+            return self.pow::<OUT>(exp.unsigned_abs(), mode);
+        }
+
+        let minor = self.minor_units.to_i128();
+        if minor == 0 {
+            return Err(DecimalError::DivisionByZero);
+        }
+        let exp = exp.unsigned_abs();
+        // |x| < 10^(digits - SCALE), so 1 / |x|^exp > 10^(exp * (SCALE - digits)).
+        let digits = minor.unsigned_abs().ilog10() + 1;
+        if u64::from(exp) * u64::from(SCALE.saturating_sub(digits)) >= MAX_WHOLE_DIGITS {
+            return Err(DecimalError::Overflow);
+        }
+        let negative = minor < 0 && exp % 2 == 1;
+        let mode = mode.for_magnitude(negative);
+        let magnitude = refine(SCALE.max(OUT), OUT, mode, |precision| {
+            let (lower, upper) = match pow_bounds(minor.unsigned_abs(), SCALE, exp, precision) {
+                Ok(bounds) => bounds,
+                // |x|^exp is at least 2^128, so its reciprocal is at most 2^-128.
+                Err(DecimalError::Overflow) => {
+                    let one = BigUint::pow10(precision);
+                    let limit = BigUint::from_u128(1).shl(128);
+                    return Ok(Some((BigUint::zero(), div_ceil(&one, &limit))));
+                }
+                Err(error) => return Err(error),
+            };
+            if lower.is_zero() {
+                // Too small to invert at this precision; retry with more digits.
+                return Ok(None);
+            }
+            let one_squared = BigUint::pow10(precision * 2);
+            Ok(Some((
+                one_squared.div_rem(&upper).0,
+                div_ceil(&one_squared, &lower),
+            )))
+        })?;
+        signed_result(magnitude, negative)
+    }
+
+    /// Square root, correctly rounded to `OUT`; negative input is a `Domain` error.
+    pub fn sqrt<const OUT: u32>(self, mode: RoundingMode) -> Result<Decimal<T, OUT>, DecimalError> {
+        self.nth_root::<OUT>(2, mode)
+    }
+
+    /// `n`th root, correctly rounded to `OUT`.
+    ///
+    /// Odd roots of negative values are negative; even roots of negative
+    /// values and `n == 0` are `Domain` errors.
+    pub fn nth_root<const OUT: u32>(
+        self,
+        n: u32,
+        mode: RoundingMode,
+    ) -> Result<Decimal<T, OUT>, DecimalError> {
+        let minor = self.minor_units.to_i128();
+        if n == 0 || (minor < 0 && n.is_multiple_of(2)) {
+            return Err(DecimalError::Domain);
+        }
+
+        let mode = mode.for_magnitude(minor < 0);
+        if n >= LOG_ROOT_DEGREE {
+            if minor == 0 {
+                return Ok(Decimal::zero());
+            }
+            let (numerator, denominator, below_one) = log_ratio(minor.unsigned_abs(), SCALE);
+            if numerator == denominator {
+                return signed_result(BigUint::pow10(OUT), minor < 0);
+            }
+            let magnitude = refine(SCALE.max(OUT), OUT, mode, |precision| {
+                root_bounds(&numerator, &denominator, below_one, n, precision).map(Some)
+            })?;
+            return signed_result(magnitude, minor < 0);
+        }
+
+        let magnitude = refine(SCALE.max(OUT), OUT, mode, |precision| {
+            // root(m / 10^SCALE) * 10^precision = root(m * 10^(n * precision - SCALE))
+            let digits = n
+                .checked_mul(precision)
+                .and_then(|digits| digits.checked_sub(SCALE))
+                .ok_or(DecimalError::Overflow)?;
+            let radicand = BigUint::from_u128(minor.unsigned_abs()).mul(&BigUint::pow10(digits));
+            let lower = radicand.nth_root(n);
+            let upper = if lower.pow(n) == radicand {
+                lower.clone()
+            } else {
+                lower.add(&BigUint::from_u128(1))
+            };
+            Ok(Some((lower, upper)))
+        })?;
+        signed_result(magnitude, minor < 0)
+    }
+}
+
+/// Evaluate bounds at increasing precision until both round to the same value.
+///
+/// `bounds(precision)` returns `lower <= exact * 10^precision <= upper`, or
+/// `None` to request more precision. The returned magnitude is at scale `out`;
+/// callers pass `mode.for_magnitude(negative)` so directed modes honour the sign.
+///
+/// Exact results that sit on a rounding boundary (e.g. `log10(1000)` under
+/// `Truncate`) never separate; once `MAX_PRECISION` digits cannot resolve the
+/// bounds the result is rounded as if it were the boundary itself. A closure
+/// still asking for more precision at that point is an `Overflow`.
+pub(crate) fn refine<F>(
+    min_precision: u32,
+    out: u32,
+    mode: RoundingMode,
+    mut bounds: F,
+) -> Result<BigUint, DecimalError>
+where
+    F: FnMut(u32) -> Result<Option<(BigUint, BigUint)>, DecimalError>,
+{
+    let first = min_precision + GUARD_DIGITS;
+    let mut precision = first;
+    loop {
+        let last = precision >= MAX_PRECISION.max(first);
+        match bounds(precision)? {
+            Some((lower, upper)) => {
+                // Anything above 2^128 in whole units cannot fit any backing at any scale.
+                if lower.bits() > BigUint::pow10(precision).bits() + 128 {
+                    return Err(DecimalError::Overflow);
+                }
+                let rounded = round_magnitude(&lower, precision, out, mode);
+                if rounded == round_magnitude(&upper, precision, out, mode) {
+                    return Ok(rounded);
+                }
+                if last {
+                    return Ok(round_boundary(&lower, precision, out, mode));
+                }
+            }
+            None if last => return Err(DecimalError::Overflow),
+            None => {}
+        }
+        precision = precision.checked_mul(2).ok_or(DecimalError::Overflow)?;
+    }
+}
+
+/// Round the first rounding boundary at or above `lower` as if it were exact.
+fn round_boundary(lower: &BigUint, precision: u32, out: u32, mode: RoundingMode) -> BigUint {
+    let unit = BigUint::pow10(precision - out);
+    let base = lower.div_rem(&unit).0.mul(&unit);
+    let boundary = match mode {
+        RoundingMode::Truncate | RoundingMode::Floor | RoundingMode::Ceiling => base.add(&unit),
+        RoundingMode::HalfUp | RoundingMode::HalfEven => {
+            let mut half = unit.clone();
+            half.mul_small(5);
+            let midpoint = base.add(&half.div_rem(&BigUint::from_u128(10)).0);
+            if midpoint >= *lower {
+                midpoint
+            } else {
+                midpoint.add(&unit)
+            }
+        }
+    };
+    round_magnitude(&boundary, precision, out, mode)
+}
+
+/// Round a non-negative value at `precision` fractional digits to `out` digits.
+fn round_magnitude(value: &BigUint, precision: u32, out: u32, mode: RoundingMode) -> BigUint {
+    round_ratio(value, &BigUint::pow10(precision - out), mode)
+}
+
+/// Round `numerator / denominator` to an integer; the denominator is non-zero.
+///
+/// Only the magnitude is seen, so `Floor` truncates and `Ceiling` rounds up.
+pub(crate) fn round_ratio(
+    numerator: &BigUint,
+    denominator: &BigUint,
+    mode: RoundingMode,
+) -> BigUint {
+    let (base, rem) = numerator.div_rem(denominator);
+    if rem.is_zero() {
+        return base;
+    }
+
+    let twice = rem.add(&rem);
+    let should_round = match mode {
+        RoundingMode::Truncate | RoundingMode::Floor => false,
+        RoundingMode::Ceiling => true,
+        RoundingMode::HalfUp => twice >= *denominator,
+        RoundingMode::HalfEven => twice > *denominator || (twice == *denominator && base.is_odd()),
+    };
+
+    if should_round {
+        base.add(&BigUint::from_u128(1))
+    } else {
+        base
+    }
+}
+
+/// Bounds on `(m / 10^scale)^exp * 10^precision` by truncating and ceiling
+/// square-and-multiply; requires `precision >= scale`.
+pub(crate) fn pow_bounds(
+    magnitude: u128,
+    scale: u32,
+    exp: u32,
+    precision: u32,
+) -> Result<(BigUint, BigUint), DecimalError> {
+    let base = BigUint::from_u128(magnitude).mul(&BigUint::pow10(precision - scale));
+    power_bounds(base.clone(), base, exp, precision)
+}
+
+/// Bounds on `x^exp * 10^precision` given `lower_base <= x * 10^precision <= upper_base`.
+///
+/// Returns `Overflow` once `x^exp` is known to be at least 2^128.
+pub(crate) fn power_bounds(
+    mut lower_base: BigUint,
+    mut upper_base: BigUint,
+    exp: u32,
+    precision: u32,
+) -> Result<(BigUint, BigUint), DecimalError> {
+    let one = BigUint::pow10(precision);
+    // Partial products only grow when the base is above one, so exceeding
+    // 2^128 in whole units means the final result overflows too.
+    let limit = one.bits() + 128;
+
+    let mut lower = one.clone();
+    let mut upper = one.clone();
+    let mut remaining = exp;
+    while remaining > 0 {
+        if remaining & 1 == 1 {
+            lower = lower.mul(&lower_base).div_rem(&one).0;
+            upper = div_ceil(&upper.mul(&upper_base), &one);
+        }
+        remaining >>= 1;
+        if remaining > 0 {
+            lower_base = lower_base.mul(&lower_base).div_rem(&one).0;
+            upper_base = div_ceil(&upper_base.mul(&upper_base), &one);
+        }
+        if lower.bits() > limit || lower_base.bits() > limit {
+            return Err(DecimalError::Overflow);
+        }
+    }
+    Ok((lower, upper))
+}
+
+pub(crate) fn div_ceil(numerator: &BigUint, denominator: &BigUint) -> BigUint {
+    let (quotient, rem) = numerator.div_rem(denominator);
+    if rem.is_zero() {
+        quotient
+    } else {
+        quotient.add(&BigUint::from_u128(1))
+    }
+}
+
+/// Apply a sign to a rounded magnitude and narrow it to the backing type.
+pub(crate) fn signed_result<T: DecimalInt, const OUT: u32>(
+    magnitude: BigUint,
+    negative: bool,
+) -> Result<Decimal<T, OUT>, DecimalError> {
+    let magnitude = magnitude.to_u128().ok_or(DecimalError::Overflow)?;
+    let value = if negative {
+        0_i128
+            .checked_sub_unsigned(magnitude)
+            .ok_or(DecimalError::Overflow)?
+    } else {
+        i128::try_from(magnitude).map_err(|_| DecimalError::Overflow)?
+    };
+    Decimal::from_i128(value)
+}
@@ -0,0 +1,28 @@
+use crate::decimal::{Decimal, DecimalError, DecimalInt, RoundingMode, round_quotient};
+
+impl<T: DecimalInt, const SCALE: u32> Decimal<T, SCALE> {
+    /// Round to a multiple of `step`, such as a tick size of `0.05`.
+    ///
+    /// Use `Floor` for bids and `Ceiling` for asks to keep prices on the
+    /// passive side. A zero step is `DivisionByZero`; a negative step is
+    /// `Invalid`.
+    pub fn quantize_to(self, step: Self, mode: RoundingMode) -> Result<Self, DecimalError> {
+        let step = step.minor_units.to_i128();
+        if step == 0 {
+            return Err(DecimalError::DivisionByZero);
+        }
+        if step < 0 {
+            return Err(DecimalError::Invalid);
+        }
+
+        let steps = round_quotient(self.minor_units.to_i128(), step, mode)?;
+        let quantized = steps.checked_mul(step).ok_or(DecimalError::Overflow)?;
+        Self::from_i128(quantized)
+    }
+
+    /// Whether `self` is an exact multiple of `step`; only zero is a multiple of zero.
+    pub fn is_multiple_of(self, step: Self) -> bool {
+        let value = self.minor_units.to_i128().unsigned_abs();
+        value.is_multiple_of(step.minor_units.to_i128().unsigned_abs())
+    }
+}
@@ -0,0 +1,23 @@
+use crate::decimal::{ArchivedDecimal, Decimal};
+
+macro_rules! archived_to_native {
+    ($t:ty) => {
+        impl<const SCALE: u32> ArchivedDecimal<$t, SCALE> {
+            /// Read the archived value without deserializing.
+            pub fn to_native(&self) -> Decimal<$t, SCALE> {
+                Decimal {
+                    minor_units: self.minor_units.to_native(),
+                }
+            }
+        }
+
+        impl<const SCALE: u32> PartialEq<Decimal<$t, SCALE>> for ArchivedDecimal<$t, SCALE> {
+            fn eq(&self, other: &Decimal<$t, SCALE>) -> bool {
+                self.to_native() == *other
+            }
+        }
+    };
+}
+
+archived_to_native!(i64);
+archived_to_native!(i128);
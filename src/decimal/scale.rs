@@ -0,0 +1,50 @@
+use crate::decimal::{Decimal, DecimalError, DecimalInt};
+
+/// Type-level sum of two scales, used to type exact products.
+///
+/// Implemented for `Decimal<T, SCALE>` and every `RHS` with
+/// `SCALE + RHS <= 18`, so an exact product that would exceed the maximum
+/// scale fails to compile instead of rounding.
+pub trait ScaleSum<const RHS: u32> {
+    /// Decimal type at scale `SCALE + RHS`.
+    type Output;
+
+    /// Build the output from a scaled integer already at `SCALE + RHS`.
+    fn from_product(product: i128) -> Result<Self::Output, DecimalError>;
+}
+
+macro_rules! impl_scale_sum {
+    ($($lhs:literal => [$($rhs:literal),*]);+ $(;)?) => {
+        $($(
+            impl<T: DecimalInt> ScaleSum<$rhs> for Decimal<T, $lhs> {
+                type Output = Decimal<T, { $lhs + $rhs }>;
+
+                fn from_product(product: i128) -> Result<Self::Output, DecimalError> {
+                    Decimal::<T, { $lhs + $rhs }>::from_i128(product)
+                }
+            }
+        )*)+
+    };
+}
+
+impl_scale_sum! {
+    0 => [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18];
+    1 => [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17];
+    2 => [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+    3 => [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+    4 => [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14];
+    5 => [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13];
+    6 => [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+    7 => [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11];
+    8 => [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    9 => [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+    10 => [0, 1, 2, 3, 4, 5, 6, 7, 8];
+    11 => [0, 1, 2, 3, 4, 5, 6, 7];
+    12 => [0, 1, 2, 3, 4, 5, 6];
+    13 => [0, 1, 2, 3, 4, 5];
+    14 => [0, 1, 2, 3, 4];
+    15 => [0, 1, 2, 3];
+    16 => [0, 1, 2];
+    17 => [0, 1];
+    18 => [0];
+}
@@ -0,0 +1,42 @@
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::postgres::types::Oid;
+use sqlx::postgres::{
+    PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres,
+};
+use sqlx::{Decode, Encode, Type};
+
+use crate::decimal::{Decimal, DecimalInt};
+
+/// Built-in OIDs of `numeric` and `numeric[]`.
+const NUMERIC: Oid = Oid(1700);
+const NUMERIC_ARRAY: Oid = Oid(1231);
+
+impl<T: DecimalInt, const SCALE: u32> Type<Postgres> for Decimal<T, SCALE> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_oid(NUMERIC)
+    }
+}
+
+impl<T: DecimalInt, const SCALE: u32> PgHasArrayType for Decimal<T, SCALE> {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::with_oid(NUMERIC_ARRAY)
+    }
+}
+
+impl<T: DecimalInt, const SCALE: u32> Encode<'_, Postgres> for Decimal<T, SCALE> {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        buf.extend_from_slice(&self.to_pg_numeric());
+        Ok(IsNull::No)
+    }
+}
+
+/// Decodes both wire formats exactly; extra significant decimals are an error.
+impl<T: DecimalInt, const SCALE: u32> Decode<'_, Postgres> for Decimal<T, SCALE> {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => Ok(Self::from_pg_numeric(value.as_bytes()?)?),
+            PgValueFormat::Text => Ok(Self::from_pg_text(value.as_str()?)?),
+        }
+    }
+}
@@ -0,0 +1,370 @@
+//! Exponentials, logarithms and real powers.
+//!
+//! Each result is correctly rounded: bounds on the exact value are refined
+//! until both round the same way. Exact results (`e^0`, `ln 1`, powers of ten
+//! under `log10` and rational powers such as `4^0.5`) are detected up front.
+//! Any other result whose bounds still straddle a rounding boundary at 256
+//! fractional digits is rounded as if it lay on the boundary, so a value
+//! within `10^-256` of one may be off by one unit in the last place.
+
+use crate::decimal::big::BigUint;
+use crate::decimal::powers::{div_ceil, refine, round_ratio, signed_result};
+use crate::decimal::{Decimal, DecimalError, DecimalInt, RoundingMode};
+
+/// Whole-number bound above `ln(2^128) ≈ 88.72`; larger arguments always overflow.
+const EXP_OVERFLOW: u128 = 89;
+
+/// Extra working digits so that `e^x` up to 2^128 keeps `precision` fractional digits.
+const EXP_GUARD_DIGITS: u32 = 60;
+
+/// Extra working digits for logarithms, covering `k * ln 2` for `k` up to a few hundred.
+const LN_GUARD_DIGITS: u32 = 16;
+
+/// Root degrees tried for an exact `powf`; a reduced fraction of 128-bit
+/// integers other than 1 has no exact root of this degree or higher.
+const EXACT_ROOT_DEGREE: u32 = 128;
+
+/// Bits allowed in `a^p` and `b^p` for an exact `powf`; larger powers are
+/// left to refinement rather than built in full.
+const EXACT_POWER_BITS: u64 = 1 << 12;
+
+impl<T: DecimalInt, const SCALE: u32> Decimal<T, SCALE> {
+    /// Natural exponential, correctly rounded to `OUT`.
+    pub fn exp<const OUT: u32>(self, mode: RoundingMode) -> Result<Decimal<T, OUT>, DecimalError> {
+        let minor = self.minor_units.to_i128();
+        if minor == 0 {
+            return Decimal::<T, 0>::from_i128(1)?.rescale::<OUT>(mode);
+        }
+        let magnitude = refine(SCALE.max(OUT), OUT, mode, |precision| {
+            let work = precision + EXP_GUARD_DIGITS;
+            let z = BigUint::from_u128(minor.unsigned_abs()).mul(&BigUint::pow10(work - SCALE));
+            exp_signed_bounds(&z, &z, minor < 0, precision, work).map(Some)
+        })?;
+        signed_result(magnitude, false)
+    }
+
+    /// Natural logarithm, correctly rounded to `OUT`; non-positive input is a `Domain` error.
+    pub fn ln<const OUT: u32>(self, mode: RoundingMode) -> Result<Decimal<T, OUT>, DecimalError> {
+        let (numerator, denominator, negative) = self.log_ratio()?;
+        if numerator == denominator {
+            return Ok(Decimal::zero());
+        }
+        let mode = mode.for_magnitude(negative);
+        let magnitude = refine(SCALE.max(OUT), OUT, mode, |precision| {
+            let work = precision + LN_GUARD_DIGITS;
+            let (lower, upper) = ln_bounds(&numerator, &denominator, work);
+            Ok(Some(narrow(&lower, &upper, work, precision)))
+        })?;
+        signed_result(magnitude, negative)
+    }
+
+    /// Base-10 logarithm, correctly rounded to `OUT`; non-positive input is a `Domain` error.
+    pub fn log10<const OUT: u32>(
+        self,
+        mode: RoundingMode,
+    ) -> Result<Decimal<T, OUT>, DecimalError> {
+        let (numerator, denominator, negative) = self.log_ratio()?;
+
+        // Exact powers of ten have exact logarithms.
+        let minor = self.minor_units.to_i128().unsigned_abs();
+        if let Some(digits) = exact_power_of_ten(minor) {
+            let value = i128::from(digits) - i128::from(SCALE);
+            return Decimal::<T, 0>::from_i128(value)?.rescale::<OUT>(mode);
+        }
+
+        let mode = mode.for_magnitude(negative);
+        let magnitude = refine(SCALE.max(OUT), OUT, mode, |precision| {
+            let work = precision + LN_GUARD_DIGITS;
+            let one = BigUint::pow10(work);
+            let (ln_lower, ln_upper) = ln_bounds(&numerator, &denominator, work);
+            let (ten_lower, ten_upper) =
+                ln_bounds(&BigUint::from_u128(10), &BigUint::from_u128(1), work);
+            let lower = ln_lower.mul(&one).div_rem(&ten_upper).0;
+            let upper = div_ceil(&ln_upper.mul(&one), &ten_lower);
+            Ok(Some(narrow(&lower, &upper, work, precision)))
+        })?;
+        signed_result(magnitude, negative)
+    }
+
+    /// Raise to a decimal power, correctly rounded to `OUT`.
+    ///
+    /// Integer exponents defer to `powi` and accept negative bases; otherwise
+    /// a negative base is a `Domain` error and zero to a negative power is a
+    /// `DivisionByZero`.
+    pub fn powf<const EXP: u32, const OUT: u32>(
+        self,
+        exponent: Decimal<T, EXP>,
+        mode: RoundingMode,
+    ) -> Result<Decimal<T, OUT>, DecimalError> {
+        let exp_minor = exponent.minor_units.to_i128();
+        let exp_factor = 10_i128.pow(EXP);
+        if exp_minor % exp_factor == 0
+            && let Ok(whole) = i32::try_from(exp_minor / exp_factor)
+        {
+            return self.powi::<OUT>(whole, mode);
+        }
+
+        let minor = self.minor_units.to_i128();
+        if minor < 0 {
+            return Err(DecimalError::Domain);
+        }
+        if minor == 0 {
+            if exp_minor < 0 {
+                return Err(DecimalError::DivisionByZero);
+            }
+            return Ok(Decimal::zero());
+        }
+        if let Some(magnitude) = exact_power(minor.unsigned_abs(), SCALE, exp_minor, EXP, OUT, mode)
+        {
+            return signed_result(magnitude, false);
+        }
+
+        let (numerator, denominator, ln_negative) = self.log_ratio()?;
+        let negative_power = ln_negative != (exp_minor < 0);
+        let exp_magnitude = BigUint::from_u128(exp_minor.unsigned_abs());
+        let exp_scale = BigUint::pow10(EXP);
+        let magnitude = refine(SCALE.max(OUT), OUT, mode, |precision| {
+            let work = precision + EXP_GUARD_DIGITS;
+            // `|y| * ln(x)` is needed to `work` digits after scaling by up to 10^38.
+            let ln_work = work + 40;
+            let (ln_lower, ln_upper) = ln_bounds(&numerator, &denominator, ln_work);
+            let drop = exp_scale.mul(&BigUint::pow10(ln_work - work));
+            let z_lower = exp_magnitude.mul(&ln_lower).div_rem(&drop).0;
+            let z_upper = div_ceil(&exp_magnitude.mul(&ln_upper), &drop);
+            exp_signed_bounds(&z_lower, &z_upper, negative_power, precision, work).map(Some)
+        })?;
+        signed_result(magnitude, false)
+    }
+
+    /// `log_ratio` of a positive value; non-positive input is a `Domain` error.
+    fn log_ratio(self) -> Result<(BigUint, BigUint, bool), DecimalError> {
+        let minor = self.minor_units.to_i128();
+        if minor <= 0 {
+            return Err(DecimalError::Domain);
+        }
+        Ok(log_ratio(minor.unsigned_abs(), SCALE))
+    }
+}
+
+/// `(numerator, denominator, negative)` with `numerator / denominator >= 1`
+/// and `ln(magnitude / 10^scale) = ±ln(numerator / denominator)`.
+pub(crate) fn log_ratio(magnitude: u128, scale: u32) -> (BigUint, BigUint, bool) {
+    let value = BigUint::from_u128(magnitude);
+    let one = BigUint::pow10(scale);
+    if value >= one {
+        (value, one, false)
+    } else {
+        (one, value, true)
+    }
+}
+
+/// Bounds on `(numerator / denominator)^(±1/n) * 10^precision`, taken as
+/// `e^(±ln(numerator / denominator) / n)`.
+pub(crate) fn root_bounds(
+    numerator: &BigUint,
+    denominator: &BigUint,
+    negative: bool,
+    n: u32,
+    precision: u32,
+) -> Result<(BigUint, BigUint), DecimalError> {
+    let work = precision + EXP_GUARD_DIGITS;
+    let (ln_lower, ln_upper) = ln_bounds(numerator, denominator, work);
+    let n = BigUint::from_u128(u128::from(n));
+    let z_lower = ln_lower.div_rem(&n).0;
+    let z_upper = div_ceil(&ln_upper, &n);
+    exp_signed_bounds(&z_lower, &z_upper, negative, precision, work)
+}
+
+/// `x^(p/q)` rounded to `out` digits when `x = magnitude / 10^scale` is the
+/// `q`-th power of a fraction `a / b`, making the result the exact `(a/b)^p`.
+fn exact_power(
+    magnitude: u128,
+    scale: u32,
+    exponent: i128,
+    exp_scale: u32,
+    out: u32,
+    mode: RoundingMode,
+) -> Option<BigUint> {
+    let (p, q) = reduce(exponent.unsigned_abs(), 10_u128.pow(exp_scale));
+    let (numerator, denominator) = reduce(magnitude, 10_u128.pow(scale));
+    let mut a = exact_root(numerator, q)?;
+    let mut b = exact_root(denominator, q)?;
+    if exponent < 0 {
+        std::mem::swap(&mut a, &mut b);
+    }
+
+    let p = u32::try_from(p).ok()?;
+    if (a.bits() + b.bits() - 2) * u64::from(p) > EXACT_POWER_BITS {
+        return None;
+    }
+    let scaled = a.pow(p).mul(&BigUint::pow10(out));
+    Some(round_ratio(&scaled, &b.pow(p), mode))
+}
+
+/// `numerator / denominator` in lowest terms.
+fn reduce(numerator: u128, denominator: u128) -> (u128, u128) {
+    let (mut gcd, mut rest) = (numerator, denominator);
+    while rest != 0 {
+        (gcd, rest) = (rest, gcd % rest);
+    }
+    (numerator / gcd, denominator / gcd)
+}
+
+/// `Some(root)` if `value` is an exact `n`-th power.
+fn exact_root(value: u128, n: u128) -> Option<BigUint> {
+    let value = BigUint::from_u128(value);
+    if value == BigUint::from_u128(1) {
+        return Some(value);
+    }
+    let n = u32::try_from(n).ok().filter(|&n| n < EXACT_ROOT_DEGREE)?;
+    let root = value.nth_root(n);
+    (root.pow(n) == value).then_some(root)
+}
+
+/// `Some(n)` if `value == 10^n`.
+fn exact_power_of_ten(mut value: u128) -> Option<u32> {
+    let mut digits = 0;
+    while value >= 10 && value.is_multiple_of(10) {
+        value /= 10;
+        digits += 1;
+    }
+    (value == 1).then_some(digits)
+}
+
+/// Reduce bounds from `work` to `precision` fractional digits, keeping them outward.
+pub(crate) fn narrow(
+    lower: &BigUint,
+    upper: &BigUint,
+    work: u32,
+    precision: u32,
+) -> (BigUint, BigUint) {
+    let drop = BigUint::pow10(work - precision);
+    (lower.div_rem(&drop).0, div_ceil(upper, &drop))
+}
+
+/// Bounds on `e^(±z)` at `precision` digits for `z` in `[z_lower, z_upper]` at `work` digits.
+fn exp_signed_bounds(
+    z_lower: &BigUint,
+    z_upper: &BigUint,
+    negative: bool,
+    precision: u32,
+    work: u32,
+) -> Result<(BigUint, BigUint), DecimalError> {
+    let one = BigUint::pow10(work);
+    if !negative {
+        if *z_lower > BigUint::from_u128(EXP_OVERFLOW).mul(&one) {
+            return Err(DecimalError::Overflow);
+        }
+        let lower = exp_bound(z_lower, &one, false);
+        let upper = exp_bound(z_upper, &one, true);
+        return Ok(narrow(&lower, &upper, work, precision));
+    }
+
+    // e^-z < 10^-precision once z > 3 * precision, so [0, 1] already bounds it.
+    let mut cutoff = BigUint::from_u128(u128::from(precision)).mul(&one);
+    cutoff.mul_small(3);
+    if *z_lower > cutoff {
+        return Ok((BigUint::zero(), BigUint::from_u128(1)));
+    }
+    let one_squared = one.mul(&one);
+    let lower = one_squared.div_rem(&exp_bound(z_upper, &one, true)).0;
+    let upper = div_ceil(&one_squared, &exp_bound(z_lower, &one, false));
+    Ok(narrow(&lower, &upper, work, precision))
+}
+
+/// Lower (`round_up == false`) or upper bound on `e^(z / one) * one` for `z >= 0`.
+fn exp_bound(z: &BigUint, one: &BigUint, round_up: bool) -> BigUint {
+    // Halve the argument below 1/2 so the Taylor series converges quickly,
+    // then square the result back up.
+    let halvings = (z.bits() + 2).saturating_sub(one.bits());
+    let divisor = BigUint::from_u128(1).shl(halvings);
+    let u = divide(z, &divisor, round_up);
+
+    let mut sum = one.clone();
+    let mut term = one.clone();
+    let mut k = 1;
+    loop {
+        term = divide(&term.mul(&u), &one.mul(&BigUint::from_u128(k)), round_up);
+        sum = sum.add(&term);
+        if term.is_zero() || (round_up && term <= BigUint::from_u128(1)) {
+            break;
+        }
+        k += 1;
+    }
+    if round_up && !term.is_zero() {
+        // Remaining terms shrink by at least 4x each and sum to under one unit.
+        sum = sum.add(&BigUint::from_u128(1));
+    }
+
+    for _ in 0..halvings {
+        sum = divide(&sum.mul(&sum), one, round_up);
+    }
+    sum
+}
+
+/// Bounds on `ln(numerator / denominator) * 10^work` for a ratio of at least one.
+pub(crate) fn ln_bounds(
+    numerator: &BigUint,
+    denominator: &BigUint,
+    work: u32,
+) -> (BigUint, BigUint) {
+    let one = BigUint::pow10(work);
+
+    // Write the ratio as 2^k * f with 1 <= f < 2, so ln = k ln 2 + ln f.
+    let mut k = numerator.bits().saturating_sub(denominator.bits());
+    while k > 0 && denominator.shl(k) > *numerator {
+        k -= 1;
+    }
+    while denominator.shl(k + 1) <= *numerator {
+        k += 1;
+    }
+
+    // ln f = 2 atanh((f - 1) / (f + 1)) and ln 2 = 2 atanh(1/3).
+    let scaled = denominator.shl(k);
+    let p = numerator.sub(&scaled);
+    let q = numerator.add(&scaled);
+    let (one_u, three) = (BigUint::from_u128(1), BigUint::from_u128(3));
+
+    let bound = |round_up: bool| {
+        let mut ln2 = atanh_bound(&one_u, &three, &one, round_up);
+        ln2.mul_small(2);
+        let mut total = atanh_bound(&p, &q, &one, round_up);
+        total.mul_small(2);
+        total.add(&ln2.mul(&BigUint::from_u128(u128::from(k))))
+    };
+    (bound(false), bound(true))
+}
+
+/// Lower or upper bound on `atanh(p / q) * one` for `0 <= p / q <= 1/3`.
+fn atanh_bound(p: &BigUint, q: &BigUint, one: &BigUint, round_up: bool) -> BigUint {
+    if p.is_zero() {
+        return BigUint::zero();
+    }
+
+    let t = divide(&p.mul(one), q, round_up);
+    let t_squared = divide(&t.mul(&t), one, round_up);
+    let mut power = t;
+    let mut sum = BigUint::zero();
+    let mut n = 1;
+    loop {
+        sum = sum.add(&divide(&power, &BigUint::from_u128(n), round_up));
+        if power.is_zero() || (round_up && power <= BigUint::from_u128(1)) {
+            break;
+        }
+        power = divide(&power.mul(&t_squared), one, round_up);
+        n += 2;
+    }
+    if round_up {
+        // Remaining terms shrink by at least 9x each and sum to under one unit.
+        sum = sum.add(&BigUint::from_u128(1));
+    }
+    sum
+}
+
+fn divide(numerator: &BigUint, denominator: &BigUint, round_up: bool) -> BigUint {
+    if round_up {
+        div_ceil(numerator, denominator)
+    } else {
+        numerator.div_rem(denominator).0
+    }
+}
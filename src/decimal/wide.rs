@@ -0,0 +1,106 @@
+/// Signed 256-bit integer used for intermediate values that exceed `i128`.
+///
+/// Stored as four little-endian `u64` limbs in two's complement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) struct I256 {
+    limbs: [u64; 4],
+}
+
+impl I256 {
+    pub(crate) const ZERO: Self = Self { limbs: [0; 4] };
+
+    pub(crate) fn from_i128(value: i128) -> Self {
+        let bits = value as u128;
+        let fill = if value < 0 { u64::MAX } else { 0 };
+        Self {
+            limbs: [bits as u64, (bits >> 64) as u64, fill, fill],
+        }
+    }
+
+    pub(crate) fn is_negative(self) -> bool {
+        self.limbs[3] >> 63 == 1
+    }
+
+    pub(crate) fn checked_add(self, rhs: Self) -> Option<Self> {
+        let mut limbs = [0_u64; 4];
+        let mut carry = false;
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let (sum, c1) = self.limbs[i].overflowing_add(rhs.limbs[i]);
+            let (sum, c2) = sum.overflowing_add(u64::from(carry));
+            *limb = sum;
+            carry = c1 || c2;
+        }
+        let result = Self { limbs };
+        // Overflow iff both operands share a sign that the result does not.
+        if self.is_negative() == rhs.is_negative() && result.is_negative() != self.is_negative() {
+            return None;
+        }
+        Some(result)
+    }
+
+    fn wrapping_neg(self) -> Self {
+        let mut limbs = self.limbs.map(|limb| !limb);
+        for limb in limbs.iter_mut() {
+            let (sum, carry) = limb.overflowing_add(1);
+            *limb = sum;
+            if !carry {
+                break;
+            }
+        }
+        Self { limbs }
+    }
+
+    pub(crate) fn checked_neg(self) -> Option<Self> {
+        let result = self.wrapping_neg();
+        // Only the minimum value negates to itself with the sign bit set.
+        if result.is_negative() && self.is_negative() {
+            return None;
+        }
+        Some(result)
+    }
+
+    pub(crate) fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_add(rhs.checked_neg()?)
+    }
+
+    /// Narrow to `i128` if the value fits.
+    pub(crate) fn to_i128(self) -> Option<i128> {
+        let low = u128::from(self.limbs[0]) | (u128::from(self.limbs[1]) << 64);
+        let value = low as i128;
+        let fill = if value < 0 { u64::MAX } else { 0 };
+        if self.limbs[2] == fill && self.limbs[3] == fill {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Divide the magnitude by `divisor`, returning `(|self| / divisor, |self| % divisor)`.
+    ///
+    /// The quotient is returned with the sign of `self`.
+    pub(crate) fn div_rem_u64(self, divisor: u64) -> (Self, u64) {
+        let negative = self.is_negative();
+        // |MIN| does not fit, but its bit pattern is the correct unsigned magnitude.
+        let magnitude = if negative {
+            self.wrapping_neg().limbs
+        } else {
+            self.limbs
+        };
+
+        let mut quotient = [0_u64; 4];
+        let mut rem = 0_u128;
+        for i in (0..4).rev() {
+            let current = (rem << 64) | u128::from(magnitude[i]);
+            quotient[i] = (current / u128::from(divisor)) as u64;
+            rem = current % u128::from(divisor);
+        }
+
+        let quotient = Self { limbs: quotient };
+        let quotient = if negative {
+            quotient.wrapping_neg()
+        } else {
+            quotient
+        };
+        (quotient, rem as u64)
+    }
+}
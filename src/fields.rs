@@ -0,0 +1,233 @@
+//! Amount fields of FIX and ISO 20022 messages.
+//!
+//! Both formats limit digits rather than magnitude. A value fits
+//! `DigitLimits { total, fractional }` if it can be written as `i · 10^-n`
+//! with `i` of at most `total` digits and `n <= fractional`, the same rule
+//! as XML Schema's `totalDigits` and `fractionDigits` facets; leading zeros
+//! and trailing fractional zeros do not count.
+
+use crate::money::{Currency, Money};
+use crate::{Decimal, DecimalError, DecimalInt};
+
+/// Total and fractional digit limits for a field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DigitLimits {
+    total: u32,
+    fractional: u32,
+}
+
+impl DigitLimits {
+    /// FIX `float` fields (`Price`, `Qty`, `Amt`, ...): fifteen significant digits.
+    pub const FIX_FLOAT: DigitLimits = DigitLimits {
+        total: 15,
+        fractional: 15,
+    };
+
+    /// ISO 20022 `ActiveCurrencyAndAmount`: eighteen digits, five after the point.
+    pub const ISO20022_AMOUNT: DigitLimits = DigitLimits {
+        total: 18,
+        fractional: 5,
+    };
+
+    /// Returns `Invalid` unless `0 < total` and `fractional <= total`.
+    pub fn new(total: u32, fractional: u32) -> Result<Self, DecimalError> {
+        if total == 0 || fractional > total {
+            return Err(DecimalError::Invalid);
+        }
+        Ok(DigitLimits { total, fractional })
+    }
+
+    pub fn total(self) -> u32 {
+        self.total
+    }
+
+    pub fn fractional(self) -> u32 {
+        self.fractional
+    }
+
+    /// Returns `TooManyFractionalDigits` or `TooManyDigits` if `value` does
+    /// not fit.
+    pub fn check<T: DecimalInt, const SCALE: u32>(
+        self,
+        value: Decimal<T, SCALE>,
+    ) -> Result<(), DecimalError> {
+        let (total, fractional) = digits(value.minor_units().to_i128(), SCALE);
+        if fractional > self.fractional {
+            return Err(DecimalError::TooManyFractionalDigits {
+                provided: fractional as usize,
+                allowed: self.fractional as usize,
+            });
+        }
+        if total > self.total {
+            return Err(DecimalError::TooManyDigits {
+                provided: total as usize,
+                allowed: self.total as usize,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Parse a FIX `float` field: an optional `-`, digits and an optional point.
+///
+/// Returns `Invalid` for other characters (FIX has no `+` or exponent), then
+/// checks `limits` before converting to `SCALE`.
+pub fn parse_fix<T, const SCALE: u32>(
+    text: &str,
+    limits: DigitLimits,
+) -> Result<Decimal<T, SCALE>, DecimalError>
+where
+    T: DecimalInt,
+{
+    let unsigned = text.strip_prefix('-').unwrap_or(text);
+    parse_checked(text, unsigned, limits)
+}
+
+/// Format for a FIX `float` field with trailing fractional zeros removed.
+///
+/// Returns the `check` error if `value` does not fit `limits`.
+pub fn format_fix<T, const SCALE: u32>(
+    value: Decimal<T, SCALE>,
+    limits: DigitLimits,
+) -> Result<String, DecimalError>
+where
+    T: DecimalInt,
+{
+    limits.check(value)?;
+    let minor = value.minor_units().to_i128();
+    let (_, fractional) = digits(minor, SCALE);
+    Ok(format_at(minor, SCALE, fractional))
+}
+
+/// Parse an ISO 20022 `ActiveCurrencyAndAmount` from its amount text and
+/// `Ccy` attribute.
+///
+/// The amount follows `xsd:decimal` and must not be negative (`Invalid`).
+/// Beyond `ISO20022_AMOUNT`, its fractional digits may not exceed the
+/// currency's minor unit, giving `TooManyFractionalDigits`.
+pub fn parse_iso20022_amount<T, const SCALE: u32>(
+    amount: &str,
+    currency: &str,
+) -> Result<Money<T, SCALE>, DecimalError>
+where
+    T: DecimalInt,
+{
+    let currency: Currency = currency.parse()?;
+    let unsigned = amount.strip_prefix(['+', '-']).unwrap_or(amount);
+    let value: Decimal<T, SCALE> = parse_checked(amount, unsigned, DigitLimits::ISO20022_AMOUNT)?;
+    if value.minor_units().to_i128() < 0 {
+        return Err(DecimalError::Invalid);
+    }
+    check_minor_unit(value, currency)?;
+    Ok(Money::new(value, currency))
+}
+
+/// Format the amount of an ISO 20022 `ActiveCurrencyAndAmount` with exactly
+/// the currency's minor-unit decimals, e.g. `1234.50` for USD and `1500`
+/// for JPY.
+///
+/// Returns `Invalid` for a negative amount and the limit errors of
+/// `parse_iso20022_amount` if it does not fit.
+pub fn format_iso20022_amount<T, const SCALE: u32>(
+    money: Money<T, SCALE>,
+) -> Result<String, DecimalError>
+where
+    T: DecimalInt,
+{
+    let value = money.amount();
+    let minor = value.minor_units().to_i128();
+    if minor < 0 {
+        return Err(DecimalError::Invalid);
+    }
+    DigitLimits::ISO20022_AMOUNT.check(value)?;
+    check_minor_unit(value, money.currency())?;
+    Ok(format_at(minor, SCALE, money.currency().minor_units()))
+}
+
+/// Validate the characters after any sign, check digit limits on the text,
+/// then parse it without its trailing fractional zeros.
+fn parse_checked<T: DecimalInt, const SCALE: u32>(
+    text: &str,
+    unsigned: &str,
+    limits: DigitLimits,
+) -> Result<Decimal<T, SCALE>, DecimalError> {
+    if text.is_empty() {
+        return Err(DecimalError::Empty);
+    }
+    let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    let all_digits = |part: &str| part.bytes().all(|byte| byte.is_ascii_digit());
+    if integer.is_empty() && fraction.is_empty() || !all_digits(integer) || !all_digits(fraction) {
+        return Err(DecimalError::Invalid);
+    }
+
+    let integer = integer.trim_start_matches('0');
+    let fraction = fraction.trim_end_matches('0');
+    let significant = format!("{integer}{fraction}");
+    let total = significant.trim_start_matches('0').len();
+    if fraction.len() > limits.fractional as usize {
+        return Err(DecimalError::TooManyFractionalDigits {
+            provided: fraction.len(),
+            allowed: limits.fractional as usize,
+        });
+    }
+    if total > limits.total as usize {
+        return Err(DecimalError::TooManyDigits {
+            provided: total,
+            allowed: limits.total as usize,
+        });
+    }
+
+    let sign = if text.starts_with('-') { "-" } else { "" };
+    format!("{sign}0{integer}.{fraction}").parse()
+}
+
+fn check_minor_unit<T: DecimalInt, const SCALE: u32>(
+    value: Decimal<T, SCALE>,
+    currency: Currency,
+) -> Result<(), DecimalError> {
+    let (_, fractional) = digits(value.minor_units().to_i128(), SCALE);
+    if fractional > currency.minor_units() {
+        return Err(DecimalError::TooManyFractionalDigits {
+            provided: fractional as usize,
+            allowed: currency.minor_units() as usize,
+        });
+    }
+    Ok(())
+}
+
+/// Significant total and fractional digits of `minor / 10^scale`.
+fn digits(minor: i128, scale: u32) -> (u32, u32) {
+    let mut magnitude = minor.unsigned_abs();
+    if magnitude == 0 {
+        return (0, 0);
+    }
+    let mut fractional = scale;
+    while fractional > 0 && magnitude.is_multiple_of(10) {
+        magnitude /= 10;
+        fractional -= 1;
+    }
+    (magnitude.ilog10() + 1, fractional)
+}
+
+/// `minor / 10^scale` written with `decimals` fractional digits; the value
+/// needs no more than that.
+fn format_at(minor: i128, scale: u32, decimals: u32) -> String {
+    let magnitude = minor.unsigned_abs();
+    let sign = if minor < 0 { "-" } else { "" };
+    let unit = 10_u128.pow(scale);
+    let (integer, fraction) = (magnitude / unit, magnitude % unit);
+    if decimals == 0 {
+        return format!("{sign}{integer}");
+    }
+
+    // Drop or add zeros to move the fraction from `scale` to `decimals` digits.
+    let fraction = if decimals <= scale {
+        fraction / 10_u128.pow(scale - decimals)
+    } else {
+        fraction * 10_u128.pow(decimals - scale)
+    };
+    format!(
+        "{sign}{integer}.{fraction:0width$}",
+        width = decimals as usize
+    )
+}
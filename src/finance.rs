@@ -0,0 +1,563 @@
+//! Time value of money and loan amortization.
+//!
+//! Cash flows follow the spreadsheet sign convention: money paid out is
+//! negative and money received is positive, so a loan taken (`pv > 0`) is
+//! repaid with negative payments. Rates are per period.
+//!
+//! Closed-form results are evaluated as exact fractions and rounded once to
+//! the amount scale using the provided `RoundingMode`; over many periods the
+//! growth factor is instead bounded until both bounds round alike. `nper`,
+//! `rate` and `irr` are correctly rounded to `OUT`.
+
+use std::cmp::Ordering;
+
+use crate::decimal::{
+    BigInt, BigUint, div_ceil, ln_bounds, narrow, power_bounds, refine, round_ratio, signed_result,
+};
+use crate::{Decimal, DecimalError, DecimalInt, RoundingMode};
+
+/// Extra working digits for the logarithms in `nper`.
+const LN_GUARD_DIGITS: u32 = 16;
+
+/// Largest rate, in whole units per period, searched by `rate` and `irr`.
+const MAX_RATE: i128 = 1_000;
+
+/// Bits in `(d + p)^n` and `d^n` together beyond which the growth factor is
+/// bounded rather than computed exactly.
+const EXACT_GROWTH_BITS: u64 = 1 << 16;
+
+/// First and last working digits for a bounded growth factor.
+const MIN_GROWTH_PRECISION: u32 = 48;
+const MAX_GROWTH_PRECISION: u32 = 384;
+
+/// Most rows `amortization_schedule` will build.
+const MAX_SCHEDULE_PERIODS: u32 = 1 << 20;
+
+/// When payments fall within each period.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PaymentTiming {
+    /// At the end of each period (ordinary annuity).
+    #[default]
+    End,
+    /// At the start of each period (annuity due).
+    Beginning,
+}
+
+/// One period of an amortization schedule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AmortizationRow<T, const SCALE: u32> {
+    /// Period number, starting at 1.
+    pub period: u32,
+    pub payment: Decimal<T, SCALE>,
+    pub interest: Decimal<T, SCALE>,
+    pub principal: Decimal<T, SCALE>,
+    /// Outstanding balance after this period's payment.
+    pub balance: Decimal<T, SCALE>,
+}
+
+/// `principal · (1 + rate)^periods`, rounded to `SCALE`.
+pub fn compound<T, const SCALE: u32, const RATE: u32>(
+    principal: Decimal<T, SCALE>,
+    rate: Decimal<T, RATE>,
+    periods: u32,
+    mode: RoundingMode,
+) -> Result<Decimal<T, SCALE>, DecimalError>
+where
+    T: DecimalInt,
+{
+    let rate = Rate::new(rate);
+    let principal = big(principal);
+    rate.at_growth(periods, |grown, base| {
+        rounded(&principal.mul(grown), base, mode)
+    })
+}
+
+/// Future value of `pv` plus `nper` payments of `pmt`, rounded to `SCALE`.
+pub fn fv<T, const SCALE: u32, const RATE: u32>(
+    rate: Decimal<T, RATE>,
+    nper: u32,
+    pmt: Decimal<T, SCALE>,
+    pv: Decimal<T, SCALE>,
+    timing: PaymentTiming,
+    mode: RoundingMode,
+) -> Result<Decimal<T, SCALE>, DecimalError>
+where
+    T: DecimalInt,
+{
+    let rate = Rate::new(rate);
+    let (pmt, pv) = (big(pmt), big(pv));
+    if rate.p.is_zero() {
+        let total = pv.add(&pmt.mul(&count(nper)));
+        return rounded(&total.neg(), &count(1), mode);
+    }
+
+    // -(pv·g + pmt·(1 + r·t)·(g - 1) / r) with g = a / b and r = p / d.
+    let payment = pmt.mul(&rate.timing(timing));
+    rate.at_growth(nper, |a, b| {
+        let numerator = pv.mul(a).mul(&rate.p).add(&payment.mul(&a.sub(b)));
+        rounded(&numerator.neg(), &b.mul(&rate.p), mode)
+    })
+}
+
+/// Present value of `nper` payments of `pmt` plus a final `fv`, rounded to `SCALE`.
+pub fn pv<T, const SCALE: u32, const RATE: u32>(
+    rate: Decimal<T, RATE>,
+    nper: u32,
+    pmt: Decimal<T, SCALE>,
+    fv: Decimal<T, SCALE>,
+    timing: PaymentTiming,
+    mode: RoundingMode,
+) -> Result<Decimal<T, SCALE>, DecimalError>
+where
+    T: DecimalInt,
+{
+    let rate = Rate::new(rate);
+    let (pmt, fv) = (big(pmt), big(fv));
+    if rate.p.is_zero() {
+        let total = fv.add(&pmt.mul(&count(nper)));
+        return rounded(&total.neg(), &count(1), mode);
+    }
+
+    let payment = pmt.mul(&rate.timing(timing));
+    rate.at_growth(nper, |a, b| {
+        let numerator = fv.mul(b).mul(&rate.p).add(&payment.mul(&a.sub(b)));
+        rounded(&numerator.neg(), &a.mul(&rate.p), mode)
+    })
+}
+
+/// Level payment that takes `pv` to `fv` over `nper` periods, rounded to `SCALE`.
+///
+/// Returns `DivisionByZero` when `nper` is zero.
+pub fn pmt<T, const SCALE: u32, const RATE: u32>(
+    rate: Decimal<T, RATE>,
+    nper: u32,
+    pv: Decimal<T, SCALE>,
+    fv: Decimal<T, SCALE>,
+    timing: PaymentTiming,
+    mode: RoundingMode,
+) -> Result<Decimal<T, SCALE>, DecimalError>
+where
+    T: DecimalInt,
+{
+    let rate = Rate::new(rate);
+    let (pv, fv) = (big(pv), big(fv));
+    if rate.p.is_zero() {
+        return rounded(&fv.add(&pv).neg(), &count(nper), mode);
+    }
+
+    let timing = rate.timing(timing);
+    rate.at_growth(nper, |a, b| {
+        let numerator = fv.mul(b).add(&pv.mul(a)).mul(&rate.p);
+        rounded(&numerator.neg(), &timing.mul(&a.sub(b)), mode)
+    })
+}
+
+/// Number of periods that takes `pv` to `fv` with payments of `pmt`,
+/// correctly rounded to `OUT`.
+///
+/// The result is generally fractional. Returns `NoSolution` when no real
+/// number of periods balances the cash flows and `DivisionByZero` for a zero
+/// rate with a zero payment.
+pub fn nper<T, const SCALE: u32, const RATE: u32, const OUT: u32>(
+    rate: Decimal<T, RATE>,
+    pmt: Decimal<T, SCALE>,
+    pv: Decimal<T, SCALE>,
+    fv: Decimal<T, SCALE>,
+    timing: PaymentTiming,
+    mode: RoundingMode,
+) -> Result<Decimal<T, OUT>, DecimalError>
+where
+    T: DecimalInt,
+{
+    let rate = Rate::new(rate);
+    let (pmt, pv, fv) = (big(pmt), big(pv), big(fv));
+    if rate.p.is_zero() {
+        let numerator = fv
+            .add(&pv)
+            .neg()
+            .mul(&BigInt::new(false, BigUint::pow10(OUT)));
+        return rounded(&numerator, &pmt, mode);
+    }
+
+    // n = ln(x / y) / ln(1 + r)
+    let annuity = pmt.mul(&rate.timing(timing));
+    let x = annuity.sub(&fv.mul(&rate.p));
+    let y = annuity.add(&pv.mul(&rate.p));
+    let growth = rate.d.add(&rate.p);
+    if x.is_zero() || y.is_zero() || x.is_negative() != y.is_negative() {
+        return Err(DecimalError::NoSolution);
+    }
+    if growth.signum() != Ordering::Greater {
+        return Err(DecimalError::Domain);
+    }
+    if x.magnitude() == y.magnitude() {
+        return Ok(Decimal::zero());
+    }
+
+    let (x, y) = (x.magnitude(), y.magnitude());
+    let (ratio_high, ratio_low) = if x > y { (x, y) } else { (y, x) };
+    let growth = growth.magnitude();
+    let base = rate.d.magnitude();
+    let (growth_high, growth_low) = if growth > base {
+        (growth, base)
+    } else {
+        (base, growth)
+    };
+    let negative = (x < y) != rate.p.is_negative();
+
+    let magnitude = refine(OUT, OUT, mode.for_magnitude(negative), |precision| {
+        let work = precision + LN_GUARD_DIGITS;
+        let one = BigUint::pow10(work);
+        let (top_lower, top_upper) = ln_bounds(ratio_high, ratio_low, work);
+        let (bottom_lower, bottom_upper) = ln_bounds(growth_high, growth_low, work);
+        if bottom_lower.is_zero() {
+            return Ok(None);
+        }
+        let lower = top_lower.mul(&one).div_rem(&bottom_upper).0;
+        let upper = div_ceil(&top_upper.mul(&one), &bottom_lower);
+        Ok(Some(narrow(&lower, &upper, work, precision)))
+    })?;
+    signed_result(magnitude, negative)
+}
+
+/// Per-period rate that takes `pv` to `fv` over `nper` payments of `pmt`,
+/// correctly rounded to `OUT`.
+///
+/// Found by bisection over rates in `(-1, 1000]`; returns `NoSolution` if
+/// the cash flows do not change sign across that range.
+pub fn rate<T, const SCALE: u32, const OUT: u32>(
+    nper: u32,
+    pmt: Decimal<T, SCALE>,
+    pv: Decimal<T, SCALE>,
+    fv: Decimal<T, SCALE>,
+    timing: PaymentTiming,
+    mode: RoundingMode,
+) -> Result<Decimal<T, OUT>, DecimalError>
+where
+    T: DecimalInt,
+{
+    let (pmt, pv, fv) = (big(pmt), big(pv), big(fv));
+    solve_rate(mode, |rate| {
+        if rate.p.is_zero() {
+            return pv.add(&pmt.mul(&count(nper))).add(&fv).signum();
+        }
+        // f(r) = pv·g + pmt·(1 + r·t)·(g - 1) / r + fv, scaled by b·p.
+        let payment = pmt.mul(&rate.timing(timing));
+        let sign = rate.at_growth(nper, |a, b| {
+            pv.mul(a)
+                .mul(&rate.p)
+                .add(&payment.mul(&a.sub(b)))
+                .add(&fv.mul(b).mul(&rate.p))
+                .signum()
+        });
+        if rate.p.is_negative() {
+            sign.reverse()
+        } else {
+            sign
+        }
+    })
+}
+
+/// Net present value `Σ cashflows[t] / (1 + rate)^t`, rounded to `SCALE`.
+///
+/// The first cash flow is at time zero and is not discounted.
+pub fn npv<T, const SCALE: u32, const RATE: u32>(
+    rate: Decimal<T, RATE>,
+    cashflows: &[Decimal<T, SCALE>],
+    mode: RoundingMode,
+) -> Result<Decimal<T, SCALE>, DecimalError>
+where
+    T: DecimalInt,
+{
+    if cashflows.is_empty() {
+        return Err(DecimalError::Empty);
+    }
+    let flows: Vec<BigInt> = cashflows.iter().map(|&flow| big(flow)).collect();
+    let (numerator, denominator) = discounted(&Rate::new(rate), &flows);
+    rounded(&numerator, &denominator, mode)
+}
+
+/// Internal rate of return: the rate at which `npv` is zero, correctly
+/// rounded to `OUT`.
+///
+/// Found by bisection over rates in `(-1, 1000]`; returns `NoSolution` if
+/// the net present value does not change sign across that range.
+pub fn irr<T, const SCALE: u32, const OUT: u32>(
+    cashflows: &[Decimal<T, SCALE>],
+    mode: RoundingMode,
+) -> Result<Decimal<T, OUT>, DecimalError>
+where
+    T: DecimalInt,
+{
+    if cashflows.is_empty() {
+        return Err(DecimalError::Empty);
+    }
+    let flows: Vec<BigInt> = cashflows.iter().map(|&flow| big(flow)).collect();
+    // The denominator is positive for every rate above -1.
+    solve_rate(mode, |rate| discounted(rate, &flows).0.signum())
+}
+
+/// Level-payment schedule for a loan of `principal` over `nper` periods.
+///
+/// Each period's interest is rounded to `SCALE`; the final payment absorbs
+/// the accumulated rounding so the principal column sums exactly to the loan
+/// and the closing balance is zero.
+///
+/// Returns `Invalid` if `nper` exceeds 2^20 periods.
+pub fn amortization_schedule<T, const SCALE: u32, const RATE: u32>(
+    principal: Decimal<T, SCALE>,
+    rate: Decimal<T, RATE>,
+    nper: u32,
+    mode: RoundingMode,
+) -> Result<Vec<AmortizationRow<T, SCALE>>, DecimalError>
+where
+    T: DecimalInt,
+{
+    if nper > MAX_SCHEDULE_PERIODS {
+        return Err(DecimalError::Invalid);
+    }
+    let outflow = pmt(
+        rate,
+        nper,
+        principal,
+        Decimal::zero(),
+        PaymentTiming::End,
+        mode,
+    )?;
+    let level_payment = Decimal::zero()
+        .checked_sub(outflow)
+        .ok_or(DecimalError::Overflow)?;
+
+    let mut balance = principal;
+    let mut rows = Vec::with_capacity(nper as usize);
+    for period in 1..=nper {
+        let interest = balance.mul(rate, mode)?;
+        let (payment, repaid) = if period == nper {
+            let payment = balance
+                .checked_add(interest)
+                .ok_or(DecimalError::Overflow)?;
+            (payment, balance)
+        } else {
+            let repaid = level_payment
+                .checked_sub(interest)
+                .ok_or(DecimalError::Overflow)?;
+            (level_payment, repaid)
+        };
+        balance = balance.checked_sub(repaid).ok_or(DecimalError::Overflow)?;
+        rows.push(AmortizationRow {
+            period,
+            payment,
+            interest,
+            principal: repaid,
+            balance,
+        });
+    }
+    Ok(rows)
+}
+
+/// A per-period rate as the exact fraction `p / d` with `d > 0`.
+struct Rate {
+    p: BigInt,
+    d: BigInt,
+}
+
+impl Rate {
+    fn new<T: DecimalInt, const RATE: u32>(rate: Decimal<T, RATE>) -> Self {
+        Rate {
+            p: big(rate),
+            d: BigInt::new(false, BigUint::pow10(RATE)),
+        }
+    }
+
+    /// `(1 + r)^n` as the fraction `(d + p)^n / d^n`.
+    fn growth(&self, n: u32) -> (BigInt, BigInt) {
+        (self.d.add(&self.p).pow(n), self.d.pow(n))
+    }
+
+    /// `f(a, b)` at the growth factor `g = (1 + r)^n = a / b`.
+    ///
+    /// Small powers are exact. Larger ones are bounded at increasing
+    /// precision until `f` agrees at both bounds, so `f` must be monotonic in
+    /// `g` between them; a factor of at least 2^128 is bounded by `1 / 0`.
+    /// If the bounds never agree, `f` is taken at the finite non-zero one.
+    fn at_growth<R, F>(&self, n: u32, f: F) -> R
+    where
+        R: PartialEq,
+        F: Fn(&BigInt, &BigInt) -> R,
+    {
+        let base = self.d.add(&self.p);
+        let d = self.d.magnitude();
+        if (base.magnitude().bits() + d.bits()) * u64::from(n) <= EXACT_GROWTH_BITS {
+            let (a, b) = self.growth(n);
+            return f(&a, &b);
+        }
+
+        let negative = base.is_negative() && n % 2 == 1;
+        let mut precision = MIN_GROWTH_PRECISION;
+        loop {
+            let one = BigUint::pow10(precision);
+            let scaled = base.magnitude().mul(&one);
+            let bounds = power_bounds(scaled.div_rem(d).0, div_ceil(&scaled, d), n, precision);
+            let ((lower, lower_den), (upper, upper_den)) = match bounds {
+                Ok((lower, upper)) => ((lower, one.clone()), (upper, one)),
+                Err(_) => (
+                    (BigUint::from_u128(1).shl(128), BigUint::from_u128(1)),
+                    (BigUint::from_u128(1), BigUint::zero()),
+                ),
+            };
+
+            let low_finite = !lower.is_zero();
+            let low = f(
+                &BigInt::new(negative, lower),
+                &BigInt::new(false, lower_den),
+            );
+            let high = f(
+                &BigInt::new(negative, upper),
+                &BigInt::new(false, upper_den),
+            );
+            if low == high {
+                return low;
+            }
+            if precision >= MAX_GROWTH_PRECISION {
+                return if low_finite { low } else { high };
+            }
+            precision *= 2;
+        }
+    }
+
+    /// `(1 + r·t) · d`, where `t` is one for payments at the start of a period.
+    fn timing(&self, timing: PaymentTiming) -> BigInt {
+        match timing {
+            PaymentTiming::End => self.d.clone(),
+            PaymentTiming::Beginning => self.d.add(&self.p),
+        }
+    }
+}
+
+/// `Σ flows[t] / (1 + r)^t` as a fraction over `(d + p)^(n - 1)`.
+///
+/// Evaluated Horner-style: each step scales the running sum by `d + p` and
+/// adds the next flow times `d^t`, so every power is built incrementally.
+fn discounted(rate: &Rate, flows: &[BigInt]) -> (BigInt, BigInt) {
+    let growth = rate.d.add(&rate.p);
+    let (first, rest) = flows.split_first().expect("discounted needs a flow");
+    let mut numerator = first.clone();
+    let mut denominator = BigInt::from_i128(1);
+    let mut discount = BigInt::from_i128(1);
+    for flow in rest {
+        discount = discount.mul(&rate.d);
+        numerator = numerator.mul(&growth).add(&flow.mul(&discount));
+        denominator = denominator.mul(&growth);
+    }
+    (numerator, denominator)
+}
+
+/// Correctly rounded root of a function known only through its sign.
+///
+/// The root is bracketed on the grid of `OUT`-digit rates between just above
+/// -1 and `MAX_RATE`, bisected down to a single grid step, and the midpoint of
+/// that step decides the rounding.
+fn solve_rate<T, const OUT: u32, F>(
+    mode: RoundingMode,
+    sign: F,
+) -> Result<Decimal<T, OUT>, DecimalError>
+where
+    T: DecimalInt,
+    F: Fn(&Rate) -> Ordering,
+{
+    let unit = 10_i128.checked_pow(OUT).ok_or(DecimalError::Overflow)?;
+    let max = unit.checked_mul(MAX_RATE).ok_or(DecimalError::Overflow)?;
+    let at = |k: i128, d: i128| {
+        sign(&Rate {
+            p: BigInt::from_i128(k),
+            d: BigInt::from_i128(d),
+        })
+    };
+
+    let mut lo = 1 - unit;
+    let lo_sign = at(lo, unit);
+    if lo_sign == Ordering::Equal {
+        return Decimal::from_i128(lo);
+    }
+    let mut hi = unit;
+    loop {
+        let hi_sign = at(hi, unit);
+        if hi_sign == Ordering::Equal {
+            return Decimal::from_i128(hi);
+        }
+        if hi_sign != lo_sign {
+            break;
+        }
+        if hi >= max {
+            return Err(DecimalError::NoSolution);
+        }
+        lo = hi;
+        hi = hi.saturating_mul(2).min(max);
+    }
+
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        match at(mid, unit) {
+            Ordering::Equal => return Decimal::from_i128(mid),
+            mid_sign if mid_sign == lo_sign => lo = mid,
+            _ => hi = mid,
+        }
+    }
+
+    // The root lies strictly between `lo` and `hi = lo + 1`.
+    let rounded = match mode {
+        RoundingMode::Floor => lo,
+        RoundingMode::Ceiling => hi,
+        RoundingMode::Truncate => {
+            if lo >= 0 {
+                lo
+            } else {
+                hi
+            }
+        }
+        RoundingMode::HalfUp | RoundingMode::HalfEven => {
+            let midpoint = BigInt::from_i128(lo).add(&BigInt::from_i128(hi));
+            let half_sign = sign(&Rate {
+                p: midpoint,
+                d: BigInt::from_i128(unit).add(&BigInt::from_i128(unit)),
+            });
+            match half_sign {
+                Ordering::Equal => match mode {
+                    RoundingMode::HalfUp if lo >= 0 => hi,
+                    RoundingMode::HalfUp => lo,
+                    _ if lo % 2 == 0 => lo,
+                    _ => hi,
+                },
+                half_sign if half_sign == lo_sign => hi,
+                _ => lo,
+            }
+        }
+    };
+    Decimal::from_i128(rounded)
+}
+
+fn big<T: DecimalInt, const SCALE: u32>(value: Decimal<T, SCALE>) -> BigInt {
+    BigInt::from_i128(value.minor_units().to_i128())
+}
+
+fn count(n: u32) -> BigInt {
+    BigInt::from_i128(i128::from(n))
+}
+
+/// Round `numerator / denominator` to an integer number of minor units.
+fn rounded<T: DecimalInt, const SCALE: u32>(
+    numerator: &BigInt,
+    denominator: &BigInt,
+    mode: RoundingMode,
+) -> Result<Decimal<T, SCALE>, DecimalError> {
+    if denominator.is_zero() {
+        return Err(DecimalError::DivisionByZero);
+    }
+    let negative = numerator.is_negative() != denominator.is_negative();
+    let magnitude = round_ratio(
+        numerator.magnitude(),
+        denominator.magnitude(),
+        mode.for_magnitude(negative),
+    );
+    signed_result(magnitude, negative)
+}
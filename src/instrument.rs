@@ -0,0 +1,87 @@
+//! Tick-size and lot-size rules for traded instruments.
+//!
+//! Exchanges accept prices only on multiples of a tick and quantities only
+//! on multiples of a lot, independent of how many decimals either carries.
+
+use crate::{Decimal, DecimalError, DecimalInt, RoundingMode};
+
+/// Price and quantity increments for one instrument.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InstrumentSpec<T, const PRICE: u32, const QTY: u32> {
+    tick_size: Decimal<T, PRICE>,
+    lot_size: Decimal<T, QTY>,
+}
+
+impl<T: DecimalInt, const PRICE: u32, const QTY: u32> InstrumentSpec<T, PRICE, QTY> {
+    /// Returns `Invalid` unless both increments are positive.
+    pub fn new(
+        tick_size: Decimal<T, PRICE>,
+        lot_size: Decimal<T, QTY>,
+    ) -> Result<Self, DecimalError> {
+        if tick_size.minor_units().to_i128() <= 0 || lot_size.minor_units().to_i128() <= 0 {
+            return Err(DecimalError::Invalid);
+        }
+        Ok(InstrumentSpec {
+            tick_size,
+            lot_size,
+        })
+    }
+
+    pub fn tick_size(self) -> Decimal<T, PRICE> {
+        self.tick_size
+    }
+
+    pub fn lot_size(self) -> Decimal<T, QTY> {
+        self.lot_size
+    }
+
+    /// `InvalidIncrement` unless `price` is on a tick.
+    pub fn validate_price(self, price: Decimal<T, PRICE>) -> Result<(), DecimalError> {
+        if price.is_multiple_of(self.tick_size) {
+            Ok(())
+        } else {
+            Err(DecimalError::InvalidIncrement)
+        }
+    }
+
+    /// `Invalid` for a non-positive quantity, `InvalidIncrement` unless it is
+    /// a whole number of lots.
+    pub fn validate_quantity(self, quantity: Decimal<T, QTY>) -> Result<(), DecimalError> {
+        if quantity.minor_units().to_i128() <= 0 {
+            return Err(DecimalError::Invalid);
+        }
+        if quantity.is_multiple_of(self.lot_size) {
+            Ok(())
+        } else {
+            Err(DecimalError::InvalidIncrement)
+        }
+    }
+
+    /// Validate an order's price and quantity together.
+    pub fn validate(
+        self,
+        price: Decimal<T, PRICE>,
+        quantity: Decimal<T, QTY>,
+    ) -> Result<(), DecimalError> {
+        self.validate_price(price)?;
+        self.validate_quantity(quantity)
+    }
+
+    /// Round a bid down to the nearest tick.
+    pub fn round_bid(self, price: Decimal<T, PRICE>) -> Result<Decimal<T, PRICE>, DecimalError> {
+        price.quantize_to(self.tick_size, RoundingMode::Floor)
+    }
+
+    /// Round an ask up to the nearest tick.
+    pub fn round_ask(self, price: Decimal<T, PRICE>) -> Result<Decimal<T, PRICE>, DecimalError> {
+        price.quantize_to(self.tick_size, RoundingMode::Ceiling)
+    }
+
+    /// Round a quantity down to whole lots.
+    pub fn round_quantity(
+        self,
+        quantity: Decimal<T, QTY>,
+    ) -> Result<Decimal<T, QTY>, DecimalError> {
+        quantity.quantize_to(self.lot_size, RoundingMode::Floor)
+    }
+}
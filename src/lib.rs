@@ -14,5 +14,21 @@
 //! ```
 
 mod decimal;
+pub mod cobol;
+pub mod columns;
+pub mod daycount;
+pub mod fields;
+pub mod finance;
+pub mod instrument;
+pub mod money;
+#[cfg(feature = "prost")]
+pub mod proto;
+pub mod stats;
+pub mod tax;
 
-pub use crate::decimal::{Decimal, DecimalError, DecimalInt, RoundingMode};
+pub use crate::decimal::{
+    AllocationStrategy, BasisPoints, Decimal, DecimalAccumulator, DecimalError, DecimalInt,
+    NORMALIZED_KEY_LEN, ParseOptions, Percent, RoundingMode, ScaleSum,
+};
+#[cfg(feature = "rkyv")]
+pub use crate::decimal::ArchivedDecimal;
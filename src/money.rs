@@ -0,0 +1,496 @@
+//! Currency-aware monetary amounts.
+//!
+//! `Money` pairs a `Decimal` amount with an ISO 4217 `Currency`. Amounts in
+//! different currencies never mix: addition and subtraction check the
+//! currency and return `DecimalError::CurrencyMismatch` instead of a sum.
+//! `ExchangeRate` converts between currencies with explicit rounding.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::decimal::{BigInt, BigUint, round_ratio, signed_result};
+use crate::{Decimal, DecimalError, DecimalInt, RoundingMode};
+
+/// An ISO 4217 currency with its minor-unit exponent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Currency {
+    code: &'static str,
+    numeric: u16,
+    minor_units: u32,
+    symbol: &'static str,
+}
+
+macro_rules! currencies {
+    ($($code:ident, $numeric:expr, $minor_units:expr, $symbol:expr;)*) => {
+        impl Currency {
+            $(
+                pub const $code: Currency = Currency {
+                    code: stringify!($code),
+                    numeric: $numeric,
+                    minor_units: $minor_units,
+                    symbol: $symbol,
+                };
+            )*
+        }
+
+        /// Active ISO 4217 currencies, ordered by code.
+        const CURRENCIES: &[Currency] = &[$(Currency::$code),*];
+    };
+}
+
+// Code, numeric code, minor units, display symbol (the code when there is no
+// widely used unambiguous symbol).
+currencies! {
+    AED, 784, 2, "AED";
+    AFN, 971, 2, "AFN";
+    ALL, 8, 2, "ALL";
+    AMD, 51, 2, "AMD";
+    AOA, 973, 2, "AOA";
+    ARS, 32, 2, "ARS";
+    AUD, 36, 2, "A$";
+    AWG, 533, 2, "AWG";
+    AZN, 944, 2, "AZN";
+    BAM, 977, 2, "BAM";
+    BBD, 52, 2, "BBD";
+    BDT, 50, 2, "BDT";
+    BGN, 975, 2, "BGN";
+    BHD, 48, 3, "BHD";
+    BIF, 108, 0, "BIF";
+    BMD, 60, 2, "BMD";
+    BND, 96, 2, "BND";
+    BOB, 68, 2, "BOB";
+    BOV, 984, 2, "BOV";
+    BRL, 986, 2, "R$";
+    BSD, 44, 2, "BSD";
+    BTN, 64, 2, "BTN";
+    BWP, 72, 2, "BWP";
+    BYN, 933, 2, "BYN";
+    BZD, 84, 2, "BZD";
+    CAD, 124, 2, "CA$";
+    CDF, 976, 2, "CDF";
+    CHE, 947, 2, "CHE";
+    CHF, 756, 2, "CHF";
+    CHW, 948, 2, "CHW";
+    CLF, 990, 4, "CLF";
+    CLP, 152, 0, "CLP";
+    CNY, 156, 2, "CN¥";
+    COP, 170, 2, "COP";
+    COU, 970, 2, "COU";
+    CRC, 188, 2, "CRC";
+    CUP, 192, 2, "CUP";
+    CVE, 132, 2, "CVE";
+    CZK, 203, 2, "CZK";
+    DJF, 262, 0, "DJF";
+    DKK, 208, 2, "DKK";
+    DOP, 214, 2, "DOP";
+    DZD, 12, 2, "DZD";
+    EGP, 818, 2, "EGP";
+    ERN, 232, 2, "ERN";
+    ETB, 230, 2, "ETB";
+    EUR, 978, 2, "€";
+    FJD, 242, 2, "FJD";
+    FKP, 238, 2, "FKP";
+    GBP, 826, 2, "£";
+    GEL, 981, 2, "GEL";
+    GHS, 936, 2, "GHS";
+    GIP, 292, 2, "GIP";
+    GMD, 270, 2, "GMD";
+    GNF, 324, 0, "GNF";
+    GTQ, 320, 2, "GTQ";
+    GYD, 328, 2, "GYD";
+    HKD, 344, 2, "HK$";
+    HNL, 340, 2, "HNL";
+    HTG, 332, 2, "HTG";
+    HUF, 348, 2, "HUF";
+    IDR, 360, 2, "IDR";
+    ILS, 376, 2, "₪";
+    INR, 356, 2, "₹";
+    IQD, 368, 3, "IQD";
+    IRR, 364, 2, "IRR";
+    ISK, 352, 0, "ISK";
+    JMD, 388, 2, "JMD";
+    JOD, 400, 3, "JOD";
+    JPY, 392, 0, "¥";
+    KES, 404, 2, "KES";
+    KGS, 417, 2, "KGS";
+    KHR, 116, 2, "KHR";
+    KMF, 174, 0, "KMF";
+    KPW, 408, 2, "KPW";
+    KRW, 410, 0, "₩";
+    KWD, 414, 3, "KWD";
+    KYD, 136, 2, "KYD";
+    KZT, 398, 2, "KZT";
+    LAK, 418, 2, "LAK";
+    LBP, 422, 2, "LBP";
+    LKR, 144, 2, "LKR";
+    LRD, 430, 2, "LRD";
+    LSL, 426, 2, "LSL";
+    LYD, 434, 3, "LYD";
+    MAD, 504, 2, "MAD";
+    MDL, 498, 2, "MDL";
+    MGA, 969, 2, "MGA";
+    MKD, 807, 2, "MKD";
+    MMK, 104, 2, "MMK";
+    MNT, 496, 2, "MNT";
+    MOP, 446, 2, "MOP";
+    MRU, 929, 2, "MRU";
+    MUR, 480, 2, "MUR";
+    MVR, 462, 2, "MVR";
+    MWK, 454, 2, "MWK";
+    MXN, 484, 2, "MX$";
+    MXV, 979, 2, "MXV";
+    MYR, 458, 2, "MYR";
+    MZN, 943, 2, "MZN";
+    NAD, 516, 2, "NAD";
+    NGN, 566, 2, "₦";
+    NIO, 558, 2, "NIO";
+    NOK, 578, 2, "NOK";
+    NPR, 524, 2, "NPR";
+    NZD, 554, 2, "NZ$";
+    OMR, 512, 3, "OMR";
+    PAB, 590, 2, "PAB";
+    PEN, 604, 2, "PEN";
+    PGK, 598, 2, "PGK";
+    PHP, 608, 2, "₱";
+    PKR, 586, 2, "PKR";
+    PLN, 985, 2, "PLN";
+    PYG, 600, 0, "PYG";
+    QAR, 634, 2, "QAR";
+    RON, 946, 2, "RON";
+    RSD, 941, 2, "RSD";
+    RUB, 643, 2, "RUB";
+    RWF, 646, 0, "RWF";
+    SAR, 682, 2, "SAR";
+    SBD, 90, 2, "SBD";
+    SCR, 690, 2, "SCR";
+    SDG, 938, 2, "SDG";
+    SEK, 752, 2, "SEK";
+    SGD, 702, 2, "SGD";
+    SHP, 654, 2, "SHP";
+    SLE, 925, 2, "SLE";
+    SOS, 706, 2, "SOS";
+    SRD, 968, 2, "SRD";
+    SSP, 728, 2, "SSP";
+    STN, 930, 2, "STN";
+    SVC, 222, 2, "SVC";
+    SYP, 760, 2, "SYP";
+    SZL, 748, 2, "SZL";
+    THB, 764, 2, "฿";
+    TJS, 972, 2, "TJS";
+    TMT, 934, 2, "TMT";
+    TND, 788, 3, "TND";
+    TOP, 776, 2, "TOP";
+    TRY, 949, 2, "₺";
+    TTD, 780, 2, "TTD";
+    TWD, 901, 2, "NT$";
+    TZS, 834, 2, "TZS";
+    UAH, 980, 2, "₴";
+    UGX, 800, 0, "UGX";
+    USD, 840, 2, "$";
+    USN, 997, 2, "USN";
+    UYI, 940, 0, "UYI";
+    UYU, 858, 2, "UYU";
+    UYW, 927, 4, "UYW";
+    UZS, 860, 2, "UZS";
+    VED, 926, 2, "VED";
+    VES, 928, 2, "VES";
+    VND, 704, 0, "₫";
+    VUV, 548, 0, "VUV";
+    WST, 882, 2, "WST";
+    XAF, 950, 0, "XAF";
+    XCD, 951, 2, "XCD";
+    XCG, 532, 2, "XCG";
+    XOF, 952, 0, "XOF";
+    XPF, 953, 0, "XPF";
+    YER, 886, 2, "YER";
+    ZAR, 710, 2, "ZAR";
+    ZMW, 967, 2, "ZMW";
+    ZWG, 924, 2, "ZWG";
+}
+
+impl Currency {
+    /// Look up an alphabetic code such as `"USD"`.
+    pub fn from_code(code: &str) -> Option<Currency> {
+        CURRENCIES
+            .iter()
+            .copied()
+            .find(|currency| currency.code == code)
+    }
+
+    /// Look up a numeric code such as `840`.
+    pub fn from_numeric(numeric: u16) -> Option<Currency> {
+        CURRENCIES
+            .iter()
+            .copied()
+            .find(|currency| currency.numeric == numeric)
+    }
+
+    /// Every currency in the built-in table.
+    pub fn all() -> &'static [Currency] {
+        CURRENCIES
+    }
+
+    /// Three-letter alphabetic code.
+    pub fn code(self) -> &'static str {
+        self.code
+    }
+
+    /// Three-digit numeric code.
+    pub fn numeric(self) -> u16 {
+        self.numeric
+    }
+
+    /// Number of decimal places in the currency's minor unit.
+    pub fn minor_units(self) -> u32 {
+        self.minor_units
+    }
+
+    /// Display symbol, or the code when the currency has none.
+    pub fn symbol(self) -> &'static str {
+        self.symbol
+    }
+}
+
+/// Parse an alphabetic code; unknown codes are `Invalid`.
+impl FromStr for Currency {
+    type Err = DecimalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(DecimalError::Empty);
+        }
+        Currency::from_code(s).ok_or(DecimalError::Invalid)
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code)
+    }
+}
+
+/// A decimal amount in a specific currency.
+///
+/// `SCALE` may be finer than the currency's minor unit (for intermediate
+/// results) or coarser (an amount known only to whole units).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Money<T, const SCALE: u32> {
+    amount: Decimal<T, SCALE>,
+    currency: Currency,
+}
+
+impl<T: DecimalInt, const SCALE: u32> Money<T, SCALE> {
+    pub fn new(amount: Decimal<T, SCALE>, currency: Currency) -> Self {
+        Money { amount, currency }
+    }
+
+    /// Zero in `currency`.
+    pub fn zero(currency: Currency) -> Self {
+        Money::new(Decimal::zero(), currency)
+    }
+
+    pub fn amount(self) -> Decimal<T, SCALE> {
+        self.amount
+    }
+
+    pub fn currency(self) -> Currency {
+        self.currency
+    }
+
+    /// Add an amount in the same currency.
+    pub fn checked_add(self, rhs: Self) -> Result<Self, DecimalError> {
+        self.same_currency(rhs)?;
+        let amount = self
+            .amount
+            .checked_add(rhs.amount)
+            .ok_or(DecimalError::Overflow)?;
+        Ok(Money::new(amount, self.currency))
+    }
+
+    /// Subtract an amount in the same currency.
+    pub fn checked_sub(self, rhs: Self) -> Result<Self, DecimalError> {
+        self.same_currency(rhs)?;
+        let amount = self
+            .amount
+            .checked_sub(rhs.amount)
+            .ok_or(DecimalError::Overflow)?;
+        Ok(Money::new(amount, self.currency))
+    }
+
+    /// Round to the currency's minor unit, keeping `SCALE`.
+    pub fn round_to_minor_unit(self, mode: RoundingMode) -> Result<Self, DecimalError> {
+        let amount = self.amount.round(self.currency.minor_units, mode)?;
+        Ok(Money::new(amount, self.currency))
+    }
+
+    /// Format with the currency symbol in front, e.g. `-$1234.50`.
+    pub fn format_with_symbol(&self) -> String
+    where
+        T: fmt::Display,
+    {
+        let amount = self.amount_text();
+        match amount.strip_prefix('-') {
+            Some(magnitude) => format!("-{}{}", self.currency.symbol, magnitude),
+            None => format!("{}{}", self.currency.symbol, amount),
+        }
+    }
+
+    fn same_currency(self, rhs: Self) -> Result<(), DecimalError> {
+        if self.currency == rhs.currency {
+            Ok(())
+        } else {
+            Err(DecimalError::CurrencyMismatch)
+        }
+    }
+
+    /// The amount with the currency's number of decimals, or with all of
+    /// `SCALE` if it carries digits below the minor unit.
+    fn amount_text(&self) -> String
+    where
+        T: fmt::Display,
+    {
+        let mut text = self.amount.to_string();
+        let minor_units = self.currency.minor_units;
+        if minor_units > SCALE {
+            if SCALE == 0 {
+                text.push('.');
+            }
+            text.extend(std::iter::repeat_n('0', (minor_units - SCALE) as usize));
+        } else if minor_units < SCALE
+            && self.amount.round(minor_units, RoundingMode::Truncate) == Ok(self.amount)
+        {
+            // Drop the trailing zeros, and the point too for whole-unit currencies.
+            let drop = (SCALE - minor_units) as usize + usize::from(minor_units == 0);
+            text.truncate(text.len() - drop);
+        }
+        text
+    }
+}
+
+/// Format with the currency code in front, e.g. `USD 1234.50`.
+impl<T: DecimalInt + fmt::Display, const SCALE: u32> fmt::Display for Money<T, SCALE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.currency.code, self.amount_text())
+    }
+}
+
+/// Price of one unit of `base` in `quote`: `1 base = rate quote`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ExchangeRate<T, const SCALE: u32> {
+    base: Currency,
+    quote: Currency,
+    rate: Decimal<T, SCALE>,
+}
+
+impl<T: DecimalInt, const SCALE: u32> ExchangeRate<T, SCALE> {
+    /// Returns `Invalid` unless the rate is positive and the currencies differ.
+    pub fn new(
+        base: Currency,
+        quote: Currency,
+        rate: Decimal<T, SCALE>,
+    ) -> Result<Self, DecimalError> {
+        if base == quote || rate.minor_units().to_i128() <= 0 {
+            return Err(DecimalError::Invalid);
+        }
+        Ok(ExchangeRate { base, quote, rate })
+    }
+
+    pub fn base(self) -> Currency {
+        self.base
+    }
+
+    pub fn quote(self) -> Currency {
+        self.quote
+    }
+
+    pub fn rate(self) -> Decimal<T, SCALE> {
+        self.rate
+    }
+
+    /// Convert an amount in either currency of the pair into the other,
+    /// rounded once to the amount's scale.
+    ///
+    /// Base amounts are multiplied by the rate and quote amounts divided by
+    /// it; any other currency is a `CurrencyMismatch`.
+    pub fn convert<const AMOUNT: u32>(
+        self,
+        amount: Money<T, AMOUNT>,
+        mode: RoundingMode,
+    ) -> Result<Money<T, AMOUNT>, DecimalError> {
+        if amount.currency == self.base {
+            let converted = amount
+                .amount
+                .mul_rescale::<SCALE, AMOUNT>(self.rate, mode)?;
+            Ok(Money::new(converted, self.quote))
+        } else if amount.currency == self.quote {
+            let converted = amount
+                .amount
+                .div_rescale::<SCALE, AMOUNT>(self.rate, mode)?;
+            Ok(Money::new(converted, self.base))
+        } else {
+            Err(DecimalError::CurrencyMismatch)
+        }
+    }
+
+    /// The reverse quote `1 / rate`, rounded to `OUT`.
+    pub fn invert<const OUT: u32>(
+        self,
+        mode: RoundingMode,
+    ) -> Result<ExchangeRate<T, OUT>, DecimalError> {
+        let rate = Decimal::<T, 0>::one().div_rescale::<SCALE, OUT>(self.rate, mode)?;
+        ExchangeRate::new(self.quote, self.base, rate)
+    }
+
+    /// Triangulate through the currency the two rates share, rounded once to `OUT`.
+    ///
+    /// The result is quoted from this rate's other currency into `other`'s
+    /// other currency, so `EUR/USD` crossed with `USD/JPY` gives `EUR/JPY`.
+    /// Rates without exactly one currency in common are a `CurrencyMismatch`.
+    pub fn cross<const RHS: u32, const OUT: u32>(
+        self,
+        other: ExchangeRate<T, RHS>,
+        mode: RoundingMode,
+    ) -> Result<ExchangeRate<T, OUT>, DecimalError> {
+        let lhs = BigInt::from_i128(self.rate.minor_units().to_i128());
+        let rhs = BigInt::from_i128(other.rate.minor_units().to_i128());
+        let lhs_one = BigInt::new(false, BigUint::pow10(SCALE));
+        let rhs_one = BigInt::new(false, BigUint::pow10(RHS));
+
+        // Each leg as a fraction of "shared currency per unit" or its inverse.
+        let (from, lhs_numerator, lhs_denominator) =
+            if self.quote == other.base || self.quote == other.quote {
+                (self.base, lhs, lhs_one)
+            } else if self.base == other.base || self.base == other.quote {
+                (self.quote, lhs_one, lhs)
+            } else {
+                return Err(DecimalError::CurrencyMismatch);
+            };
+        let shared = if from == self.base {
+            self.quote
+        } else {
+            self.base
+        };
+        let (to, rhs_numerator, rhs_denominator) = if other.base == shared {
+            (other.quote, rhs, rhs_one)
+        } else {
+            (other.base, rhs_one, rhs)
+        };
+        if from == to {
+            return Err(DecimalError::CurrencyMismatch);
+        }
+
+        let numerator = lhs_numerator
+            .mul(&rhs_numerator)
+            .mul(&BigInt::new(false, BigUint::pow10(OUT)));
+        let denominator = lhs_denominator.mul(&rhs_denominator);
+        let magnitude = round_ratio(numerator.magnitude(), denominator.magnitude(), mode);
+        ExchangeRate::new(from, to, signed_result(magnitude, false)?)
+    }
+}
+
+/// Format as `BASE/QUOTE rate`, e.g. `EUR/USD 1.0850`.
+impl<T: DecimalInt + fmt::Display, const SCALE: u32> fmt::Display for ExchangeRate<T, SCALE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{} {}", self.base.code, self.quote.code, self.rate)
+    }
+}
@@ -0,0 +1,227 @@
+//! Protocol Buffers messages `google.type.Decimal` and `google.type.Money`.
+//!
+//! The messages are declared here with `prost` so no code generation is
+//! needed; their field numbers match `google/type/decimal.proto` and
+//! `google/type/money.proto`.
+//!
+//! `Money` carries whole `units` plus `nanos` (billionths). Conversions
+//! check that `nanos` lies within `±999_999_999` and has the same sign as
+//! `units`, and either convert exactly or round with a `RoundingMode` when
+//! one side holds more decimals than the other.
+
+use crate::decimal::round_quotient;
+use crate::money::Currency;
+use crate::{DecimalError, DecimalInt, RoundingMode};
+
+/// Decimals carried by `nanos`.
+const NANO_SCALE: u32 = 9;
+const NANOS_PER_UNIT: i128 = 1_000_000_000;
+
+/// `google.type.Decimal`: a decimal number as a string.
+#[derive(Clone, PartialEq, Eq, Hash, prost::Message)]
+pub struct Decimal {
+    #[prost(string, tag = "1")]
+    pub value: String,
+}
+
+/// `google.type.Money`: an amount with its ISO 4217 currency code.
+#[derive(Clone, PartialEq, Eq, Hash, prost::Message)]
+pub struct Money {
+    #[prost(string, tag = "1")]
+    pub currency_code: String,
+    #[prost(int64, tag = "2")]
+    pub units: i64,
+    #[prost(int32, tag = "3")]
+    pub nanos: i32,
+}
+
+impl<T: DecimalInt + std::fmt::Display, const SCALE: u32> From<crate::Decimal<T, SCALE>>
+    for Decimal
+{
+    fn from(value: crate::Decimal<T, SCALE>) -> Self {
+        Decimal {
+            value: value.to_string(),
+        }
+    }
+}
+
+/// Parse the `google.type.Decimal` grammar: an optional sign, digits with an
+/// optional fraction, and an optional exponent such as `1.5e-3`.
+///
+/// An empty string is zero, as the message definition asks. Trailing zeros
+/// beyond `SCALE` are accepted; other extra digits are
+/// `TooManyFractionalDigits`. `NaN` and infinities are `NonFinite`.
+impl<T: DecimalInt, const SCALE: u32> TryFrom<&Decimal> for crate::Decimal<T, SCALE> {
+    type Error = DecimalError;
+
+    fn try_from(message: &Decimal) -> Result<Self, Self::Error> {
+        let text = message.value.as_str();
+        if text.is_empty() {
+            return Ok(crate::Decimal::from_minor_units(T::ZERO));
+        }
+
+        let (negative, unsigned) = match text.as_bytes()[0] {
+            b'-' => (true, &text[1..]),
+            b'+' => (false, &text[1..]),
+            _ => (false, text),
+        };
+        if ["nan", "inf", "infinity"]
+            .iter()
+            .any(|special| unsigned.eq_ignore_ascii_case(special))
+        {
+            return Err(DecimalError::NonFinite);
+        }
+
+        let (mantissa, exponent) = match unsigned.split_once(['e', 'E']) {
+            Some((mantissa, exponent)) => (mantissa, parse_exponent(exponent)?),
+            None => (unsigned, 0),
+        };
+        let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        let all_digits = |part: &str| part.bytes().all(|byte| byte.is_ascii_digit());
+        if integer.is_empty() && fraction.is_empty()
+            || !all_digits(integer)
+            || !all_digits(fraction)
+        {
+            return Err(DecimalError::Invalid);
+        }
+
+        // value = digits · 10^exponent, with insignificant zeros removed.
+        let digits = format!("{integer}{fraction}");
+        let digits = digits.trim_start_matches('0');
+        let significant = digits.trim_end_matches('0');
+        let trailing_zeros = digits.len() - significant.len();
+        let exponent = exponent - fraction.len() as i64 + trailing_zeros as i64;
+        if significant.is_empty() {
+            return Ok(crate::Decimal::from_minor_units(T::ZERO));
+        }
+
+        let shift = exponent + i64::from(SCALE);
+        if shift < 0 {
+            return Err(DecimalError::TooManyFractionalDigits {
+                provided: exponent.unsigned_abs() as usize,
+                allowed: SCALE as usize,
+            });
+        }
+        // i128 holds at most 39 digits.
+        if significant.len() as i64 + shift > 39 {
+            return Err(DecimalError::Overflow);
+        }
+        let minor = significant
+            .parse::<i128>()
+            .ok()
+            .and_then(|value| value.checked_mul(10_i128.pow(shift as u32)))
+            .ok_or(DecimalError::Overflow)?;
+        crate::Decimal::from_i128(if negative { -minor } else { minor })
+    }
+}
+
+impl<T: DecimalInt, const SCALE: u32> TryFrom<Decimal> for crate::Decimal<T, SCALE> {
+    type Error = DecimalError;
+
+    fn try_from(message: Decimal) -> Result<Self, Self::Error> {
+        Self::try_from(&message)
+    }
+}
+
+impl<T: DecimalInt, const SCALE: u32> crate::money::Money<T, SCALE> {
+    /// Convert to `google.type.Money`, rounding to nanos if `SCALE` exceeds 9.
+    ///
+    /// Returns `Overflow` if the whole units do not fit an `i64`.
+    pub fn to_proto(self, mode: RoundingMode) -> Result<Money, DecimalError> {
+        let minor = self.amount().minor_units().to_i128();
+        let nanos = rescale(minor, SCALE, NANO_SCALE, Some(mode))?;
+        Ok(Money {
+            currency_code: self.currency().code().to_string(),
+            units: i64::try_from(nanos / NANOS_PER_UNIT).map_err(|_| DecimalError::Overflow)?,
+            nanos: (nanos % NANOS_PER_UNIT) as i32,
+        })
+    }
+
+    /// Convert from `google.type.Money`, rounding to `SCALE` if it is below 9.
+    ///
+    /// Returns `Invalid` if `nanos` is out of range or its sign disagrees
+    /// with `units`, and `Invalid` or `Empty` for a bad currency code.
+    pub fn from_proto(message: &Money, mode: RoundingMode) -> Result<Self, DecimalError> {
+        Self::decode_proto(message, Some(mode))
+    }
+
+    fn decode_proto(message: &Money, mode: Option<RoundingMode>) -> Result<Self, DecimalError> {
+        let (units, nanos) = (message.units, message.nanos);
+        let nanos_in_range = nanos.unsigned_abs() < NANOS_PER_UNIT as u32;
+        let signs_agree = units == 0 || nanos == 0 || (units < 0) == (nanos < 0);
+        if !nanos_in_range || !signs_agree {
+            return Err(DecimalError::Invalid);
+        }
+
+        let currency: Currency = message.currency_code.parse()?;
+        let total = i128::from(units) * NANOS_PER_UNIT + i128::from(nanos);
+        let minor = rescale(total, NANO_SCALE, SCALE, mode)?;
+        Ok(Self::new(crate::Decimal::from_i128(minor)?, currency))
+    }
+}
+
+/// Exact conversion; `TooManyFractionalDigits` if `SCALE` exceeds 9 and the
+/// amount has digits below a nano.
+impl<T: DecimalInt, const SCALE: u32> TryFrom<crate::money::Money<T, SCALE>> for Money {
+    type Error = DecimalError;
+
+    fn try_from(money: crate::money::Money<T, SCALE>) -> Result<Self, Self::Error> {
+        let minor = money.amount().minor_units().to_i128();
+        rescale(minor, SCALE, NANO_SCALE, None)?;
+        money.to_proto(RoundingMode::Truncate)
+    }
+}
+
+/// Exact conversion; `TooManyFractionalDigits` if `SCALE` is below 9 and the
+/// nanos need more decimals.
+impl<T: DecimalInt, const SCALE: u32> TryFrom<&Money> for crate::money::Money<T, SCALE> {
+    type Error = DecimalError;
+
+    fn try_from(message: &Money) -> Result<Self, Self::Error> {
+        Self::decode_proto(message, None)
+    }
+}
+
+fn parse_exponent(text: &str) -> Result<i64, DecimalError> {
+    let digits = text.strip_prefix(['+', '-']).unwrap_or(text);
+    if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(DecimalError::Invalid);
+    }
+    // Exponents this large overflow or underflow every supported scale anyway.
+    text.parse::<i32>()
+        .map(i64::from)
+        .map_err(|_| DecimalError::Overflow)
+}
+
+/// Move `minor` from scale `from` to scale `to`, rounding with `mode` or,
+/// without one, failing if digits would be lost.
+fn rescale(
+    minor: i128,
+    from: u32,
+    to: u32,
+    mode: Option<RoundingMode>,
+) -> Result<i128, DecimalError> {
+    if to >= from {
+        return minor
+            .checked_mul(10_i128.pow(to - from))
+            .ok_or(DecimalError::Overflow);
+    }
+
+    let factor = 10_i128.pow(from - to);
+    match mode {
+        Some(mode) => round_quotient(minor, factor, mode),
+        None if minor % factor == 0 => Ok(minor / factor),
+        None => {
+            let mut digits = from;
+            let mut rest = minor;
+            while rest % 10 == 0 {
+                rest /= 10;
+                digits -= 1;
+            }
+            Err(DecimalError::TooManyFractionalDigits {
+                provided: digits as usize,
+                allowed: to as usize,
+            })
+        }
+    }
+}
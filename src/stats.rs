@@ -0,0 +1,237 @@
+//! Exact summary statistics over decimal series.
+//!
+//! Every function computes from the scaled integers and rounds once, at the
+//! end, to the input scale using the provided `RoundingMode`. Empty input is
+//! reported as `DecimalError::Empty`.
+
+use crate::decimal::{
+    BigInt, BigUint, round_quotient, round_ratio, round_sqrt_ratio, signed_result,
+};
+use crate::{Decimal, DecimalAccumulator, DecimalError, DecimalInt, RoundingMode};
+
+/// Arithmetic mean, rounded to `SCALE`.
+pub fn mean<T, const SCALE: u32, I>(
+    values: I,
+    mode: RoundingMode,
+) -> Result<Decimal<T, SCALE>, DecimalError>
+where
+    T: DecimalInt,
+    I: IntoIterator<Item = Decimal<T, SCALE>>,
+{
+    let accumulator: DecimalAccumulator<T, SCALE> = values.into_iter().collect();
+    if accumulator.is_empty() {
+        return Err(DecimalError::Empty);
+    }
+    accumulator.mean(mode)
+}
+
+/// Weighted mean `Σ value·weight / Σ weight`, rounded to `SCALE`.
+///
+/// With prices as values and quantities as weights this is the VWAP.
+/// Returns `DivisionByZero` if the weights sum to zero.
+pub fn weighted_mean<T, const SCALE: u32, const WEIGHT: u32, I>(
+    pairs: I,
+    mode: RoundingMode,
+) -> Result<Decimal<T, SCALE>, DecimalError>
+where
+    T: DecimalInt,
+    I: IntoIterator<Item = (Decimal<T, SCALE>, Decimal<T, WEIGHT>)>,
+{
+    // The totals are unbounded so that `i128` products and their sums cannot
+    // overflow; only the final quotient is narrowed.
+    let mut weighted_total = BigInt::zero();
+    let mut weight_total = BigInt::zero();
+    let mut count = 0_usize;
+    for (value, weight) in pairs {
+        let weight = BigInt::from_i128(weight.minor_units().to_i128());
+        let value = BigInt::from_i128(value.minor_units().to_i128());
+        weighted_total = weighted_total.add(&value.mul(&weight));
+        weight_total = weight_total.add(&weight);
+        count += 1;
+    }
+
+    if count == 0 {
+        return Err(DecimalError::Empty);
+    }
+    if weight_total.is_zero() {
+        return Err(DecimalError::DivisionByZero);
+    }
+
+    // Both totals carry the weight scale, so the quotient is already at `SCALE`.
+    let negative = weighted_total.is_negative() != weight_total.is_negative();
+    let magnitude = round_ratio(
+        weighted_total.magnitude(),
+        weight_total.magnitude(),
+        mode.for_magnitude(negative),
+    );
+    signed_result(magnitude, negative)
+}
+
+/// Median; for an even count the two middle values are averaged and rounded.
+pub fn median<T, const SCALE: u32>(
+    values: &[Decimal<T, SCALE>],
+    mode: RoundingMode,
+) -> Result<Decimal<T, SCALE>, DecimalError>
+where
+    T: DecimalInt,
+{
+    if values.is_empty() {
+        return Err(DecimalError::Empty);
+    }
+
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable();
+    let mid = sorted.len() / 2;
+    if sorted.len() % 2 == 1 {
+        return Ok(sorted[mid]);
+    }
+
+    let total = sorted[mid - 1]
+        .minor_units()
+        .to_i128()
+        .checked_add(sorted[mid].minor_units().to_i128())
+        .ok_or(DecimalError::Overflow)?;
+    Decimal::from_i128(round_quotient(total, 2, mode)?)
+}
+
+/// Population variance (divides by `n`), rounded to `SCALE`.
+///
+/// The result is in squared units but is reported at the input scale.
+pub fn variance<T, const SCALE: u32, I>(
+    values: I,
+    mode: RoundingMode,
+) -> Result<Decimal<T, SCALE>, DecimalError>
+where
+    T: DecimalInt,
+    I: IntoIterator<Item = Decimal<T, SCALE>>,
+{
+    let moments = Moments::collect(values)?;
+    let denominator = moments.population_divisor()?;
+    moments.variance(denominator, mode)
+}
+
+/// Sample variance (divides by `n - 1`), rounded to `SCALE`.
+///
+/// Returns `DivisionByZero` for a single value.
+pub fn sample_variance<T, const SCALE: u32, I>(
+    values: I,
+    mode: RoundingMode,
+) -> Result<Decimal<T, SCALE>, DecimalError>
+where
+    T: DecimalInt,
+    I: IntoIterator<Item = Decimal<T, SCALE>>,
+{
+    let moments = Moments::collect(values)?;
+    let denominator = moments.sample_divisor()?;
+    moments.variance(denominator, mode)
+}
+
+/// Population standard deviation, correctly rounded to `SCALE`.
+pub fn std_dev<T, const SCALE: u32, I>(
+    values: I,
+    mode: RoundingMode,
+) -> Result<Decimal<T, SCALE>, DecimalError>
+where
+    T: DecimalInt,
+    I: IntoIterator<Item = Decimal<T, SCALE>>,
+{
+    let moments = Moments::collect(values)?;
+    let denominator = moments.population_divisor()?;
+    moments.std_dev(denominator, mode)
+}
+
+/// Sample standard deviation, correctly rounded to `SCALE`.
+///
+/// Returns `DivisionByZero` for a single value.
+pub fn sample_std_dev<T, const SCALE: u32, I>(
+    values: I,
+    mode: RoundingMode,
+) -> Result<Decimal<T, SCALE>, DecimalError>
+where
+    T: DecimalInt,
+    I: IntoIterator<Item = Decimal<T, SCALE>>,
+{
+    let moments = Moments::collect(values)?;
+    let denominator = moments.sample_divisor()?;
+    moments.std_dev(denominator, mode)
+}
+
+/// Count, sum and sum of squares of the scaled integers.
+///
+/// The sums are unbounded so that squaring `i128` minor units cannot overflow.
+struct Moments {
+    count: u128,
+    sum: BigInt,
+    sum_squares: BigUint,
+}
+
+impl Moments {
+    fn collect<T: DecimalInt, const SCALE: u32, I>(values: I) -> Result<Self, DecimalError>
+    where
+        I: IntoIterator<Item = Decimal<T, SCALE>>,
+    {
+        let mut moments = Moments {
+            count: 0,
+            sum: BigInt::zero(),
+            sum_squares: BigUint::zero(),
+        };
+        for value in values {
+            let minor = value.minor_units().to_i128();
+            let magnitude = BigUint::from_u128(minor.unsigned_abs());
+            moments.count += 1;
+            moments.sum = moments.sum.add(&BigInt::from_i128(minor));
+            moments.sum_squares = moments.sum_squares.add(&magnitude.mul(&magnitude));
+        }
+
+        if moments.count == 0 {
+            return Err(DecimalError::Empty);
+        }
+        Ok(moments)
+    }
+
+    /// `n · Σx² − (Σx)²`, i.e. `n²` times the population variance at scale `2·SCALE`.
+    fn spread(&self) -> BigUint {
+        let sum = self.sum.magnitude();
+        // Non-negative by the Cauchy-Schwarz inequality.
+        self.sum_squares
+            .mul(&BigUint::from_u128(self.count))
+            .sub(&sum.mul(sum))
+    }
+
+    fn population_divisor(&self) -> Result<BigUint, DecimalError> {
+        self.count
+            .checked_mul(self.count)
+            .map(BigUint::from_u128)
+            .ok_or(DecimalError::Overflow)
+    }
+
+    fn sample_divisor(&self) -> Result<BigUint, DecimalError> {
+        if self.count < 2 {
+            return Err(DecimalError::DivisionByZero);
+        }
+        self.count
+            .checked_mul(self.count - 1)
+            .map(BigUint::from_u128)
+            .ok_or(DecimalError::Overflow)
+    }
+
+    fn variance<T: DecimalInt, const SCALE: u32>(
+        &self,
+        divisor: BigUint,
+        mode: RoundingMode,
+    ) -> Result<Decimal<T, SCALE>, DecimalError> {
+        // Divide out the extra `10^SCALE` from squaring along with the count.
+        let denominator = divisor.mul(&BigUint::pow10(SCALE));
+        signed_result(round_ratio(&self.spread(), &denominator, mode), false)
+    }
+
+    fn std_dev<T: DecimalInt, const SCALE: u32>(
+        &self,
+        divisor: BigUint,
+        mode: RoundingMode,
+    ) -> Result<Decimal<T, SCALE>, DecimalError> {
+        // The square root of the spread is already at `SCALE`.
+        let root = round_sqrt_ratio(&self.spread(), &divisor, mode)?;
+        signed_result(root, false)
+    }
+}
@@ -0,0 +1,255 @@
+//! Sales tax and VAT with line- or document-level rounding.
+//!
+//! Rates are fractions (`0.20` for 20%). Line amounts are either net of tax
+//! (`TaxTreatment::Exclusive`) or include it (`TaxTreatment::Inclusive`), in
+//! which case the tax is back-calculated as `gross · rate / (1 + rate)`.
+//! Each tax figure is computed exactly and rounded once to the amount scale.
+//!
+//! A `TaxBreakdown` always reconciles: line amounts sum to the per-rate
+//! amounts, which sum to the document totals, and `net + tax == gross`
+//! everywhere.
+
+use std::cmp::Reverse;
+
+use crate::decimal::round_quotient;
+use crate::{Decimal, DecimalError, DecimalInt, RoundingMode};
+
+/// Whether line amounts exclude or include tax.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TaxTreatment {
+    /// Amounts are net; tax is added on top.
+    #[default]
+    Exclusive,
+    /// Amounts are gross; tax is extracted from them.
+    Inclusive,
+}
+
+/// Where tax is rounded to the amount scale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TaxRounding {
+    /// Round the tax on every line; totals are sums of rounded lines.
+    #[default]
+    Line,
+    /// Round the tax on each rate's total; lines receive shares of it.
+    ///
+    /// Line shares are the exact line taxes rounded down, with the units left
+    /// over going to the lines with the largest remainders.
+    Document,
+}
+
+/// Net, tax and gross amounts at one rate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TaxAmounts<T, const SCALE: u32, const RATE: u32> {
+    pub rate: Decimal<T, RATE>,
+    pub net: Decimal<T, SCALE>,
+    pub tax: Decimal<T, SCALE>,
+    pub gross: Decimal<T, SCALE>,
+}
+
+/// Itemized tax for a document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaxBreakdown<T, const SCALE: u32, const RATE: u32> {
+    /// One entry per input line, in input order.
+    pub lines: Vec<TaxAmounts<T, SCALE, RATE>>,
+    /// One entry per distinct rate, in order of first appearance.
+    pub rates: Vec<TaxAmounts<T, SCALE, RATE>>,
+    pub net: Decimal<T, SCALE>,
+    pub tax: Decimal<T, SCALE>,
+    pub gross: Decimal<T, SCALE>,
+}
+
+/// Tax due on a net amount, rounded to `SCALE`.
+///
+/// Returns `Invalid` for a negative rate.
+pub fn tax_on_net<T, const SCALE: u32, const RATE: u32>(
+    net: Decimal<T, SCALE>,
+    rate: Decimal<T, RATE>,
+    mode: RoundingMode,
+) -> Result<Decimal<T, SCALE>, DecimalError>
+where
+    T: DecimalInt,
+{
+    let rate = Rate::new(rate, TaxTreatment::Exclusive)?;
+    Decimal::from_i128(rate.round(rate.numerator(net)?, mode)?)
+}
+
+/// Tax contained in a gross amount, rounded to `SCALE`.
+///
+/// The net amount is `gross - tax`. Returns `Invalid` for a negative rate.
+pub fn tax_in_gross<T, const SCALE: u32, const RATE: u32>(
+    gross: Decimal<T, SCALE>,
+    rate: Decimal<T, RATE>,
+    mode: RoundingMode,
+) -> Result<Decimal<T, SCALE>, DecimalError>
+where
+    T: DecimalInt,
+{
+    let rate = Rate::new(rate, TaxTreatment::Inclusive)?;
+    Decimal::from_i128(rate.round(rate.numerator(gross)?, mode)?)
+}
+
+/// Itemize the tax on `(amount, rate)` lines.
+///
+/// Returns `Empty` for no lines and `Invalid` for a negative rate.
+pub fn breakdown<T, const SCALE: u32, const RATE: u32>(
+    lines: &[(Decimal<T, SCALE>, Decimal<T, RATE>)],
+    treatment: TaxTreatment,
+    rounding: TaxRounding,
+    mode: RoundingMode,
+) -> Result<TaxBreakdown<T, SCALE, RATE>, DecimalError>
+where
+    T: DecimalInt,
+{
+    if lines.is_empty() {
+        return Err(DecimalError::Empty);
+    }
+
+    // Group line indices by rate, keeping first-appearance order.
+    let mut groups: Vec<(Decimal<T, RATE>, Vec<usize>)> = Vec::new();
+    for (index, &(_, rate)) in lines.iter().enumerate() {
+        match groups
+            .iter_mut()
+            .find(|(group_rate, _)| *group_rate == rate)
+        {
+            Some((_, indices)) => indices.push(index),
+            None => groups.push((rate, vec![index])),
+        }
+    }
+
+    let mut line_taxes = vec![0_i128; lines.len()];
+    for (rate, indices) in &groups {
+        let rate = Rate::new(*rate, treatment)?;
+        let numerators = indices
+            .iter()
+            .map(|&index| rate.numerator(lines[index].0))
+            .collect::<Result<Vec<_>, _>>()?;
+        let taxes = match rounding {
+            TaxRounding::Line => numerators
+                .iter()
+                .map(|&numerator| rate.round(numerator, mode))
+                .collect::<Result<Vec<_>, _>>()?,
+            TaxRounding::Document => rate.share(&numerators, mode)?,
+        };
+        for (&index, tax) in indices.iter().zip(taxes) {
+            line_taxes[index] = tax;
+        }
+    }
+
+    let mut items = Vec::with_capacity(lines.len());
+    for (&(amount, rate), &tax) in lines.iter().zip(&line_taxes) {
+        items.push(amounts(
+            rate,
+            amount.minor_units().to_i128(),
+            tax,
+            treatment,
+        )?);
+    }
+
+    let mut rates = Vec::with_capacity(groups.len());
+    for (rate, indices) in &groups {
+        let amount = checked_sum(
+            indices
+                .iter()
+                .map(|&index| lines[index].0.minor_units().to_i128()),
+        )?;
+        let tax = checked_sum(indices.iter().map(|&index| line_taxes[index]))?;
+        rates.push(amounts(*rate, amount, tax, treatment)?);
+    }
+
+    let net = checked_sum(items.iter().map(|item| item.net.minor_units().to_i128()))?;
+    let tax = checked_sum(line_taxes.iter().copied())?;
+    let gross = checked_sum(items.iter().map(|item| item.gross.minor_units().to_i128()))?;
+    Ok(TaxBreakdown {
+        lines: items,
+        rates,
+        net: Decimal::from_i128(net)?,
+        tax: Decimal::from_i128(tax)?,
+        gross: Decimal::from_i128(gross)?,
+    })
+}
+
+/// A rate as the exact fraction `amount · rate / denominator`.
+struct Rate {
+    rate: i128,
+    denominator: i128,
+}
+
+impl Rate {
+    fn new<T: DecimalInt, const RATE: u32>(
+        rate: Decimal<T, RATE>,
+        treatment: TaxTreatment,
+    ) -> Result<Self, DecimalError> {
+        let rate = rate.minor_units().to_i128();
+        if rate < 0 {
+            return Err(DecimalError::Invalid);
+        }
+        let one = 10_i128.checked_pow(RATE).ok_or(DecimalError::Overflow)?;
+        let denominator = match treatment {
+            TaxTreatment::Exclusive => one,
+            TaxTreatment::Inclusive => one.checked_add(rate).ok_or(DecimalError::Overflow)?,
+        };
+        Ok(Rate { rate, denominator })
+    }
+
+    fn numerator<T: DecimalInt, const SCALE: u32>(
+        &self,
+        amount: Decimal<T, SCALE>,
+    ) -> Result<i128, DecimalError> {
+        amount
+            .minor_units()
+            .to_i128()
+            .checked_mul(self.rate)
+            .ok_or(DecimalError::Overflow)
+    }
+
+    fn round(&self, numerator: i128, mode: RoundingMode) -> Result<i128, DecimalError> {
+        round_quotient(numerator, self.denominator, mode)
+    }
+
+    /// Round the sum of `numerators` once and split it across them.
+    fn share(&self, numerators: &[i128], mode: RoundingMode) -> Result<Vec<i128>, DecimalError> {
+        let total = self.round(checked_sum(numerators.iter().copied())?, mode)?;
+        let mut taxes: Vec<i128> = numerators
+            .iter()
+            .map(|numerator| numerator.div_euclid(self.denominator))
+            .collect();
+
+        // The rounded total lies between the sum of floors and that sum plus
+        // the number of lines with a remainder, so one pass suffices.
+        let leftover = total - taxes.iter().sum::<i128>();
+        let mut order: Vec<usize> = (0..numerators.len()).collect();
+        order.sort_by_key(|&index| Reverse(numerators[index].rem_euclid(self.denominator)));
+        for &index in order.iter().take(leftover as usize) {
+            taxes[index] += 1;
+        }
+        Ok(taxes)
+    }
+}
+
+/// Split a line or rate amount into net, tax and gross.
+fn amounts<T: DecimalInt, const SCALE: u32, const RATE: u32>(
+    rate: Decimal<T, RATE>,
+    amount: i128,
+    tax: i128,
+    treatment: TaxTreatment,
+) -> Result<TaxAmounts<T, SCALE, RATE>, DecimalError> {
+    let (net, gross) = match treatment {
+        TaxTreatment::Exclusive => (Some(amount), amount.checked_add(tax)),
+        TaxTreatment::Inclusive => (amount.checked_sub(tax), Some(amount)),
+    };
+    let (Some(net), Some(gross)) = (net, gross) else {
+        return Err(DecimalError::Overflow);
+    };
+    Ok(TaxAmounts {
+        rate,
+        net: Decimal::from_i128(net)?,
+        tax: Decimal::from_i128(tax)?,
+        gross: Decimal::from_i128(gross)?,
+    })
+}
+
+fn checked_sum(values: impl IntoIterator<Item = i128>) -> Result<i128, DecimalError> {
+    values.into_iter().try_fold(0_i128, |total, value| {
+        total.checked_add(value).ok_or(DecimalError::Overflow)
+    })
+}
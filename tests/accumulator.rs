@@ -0,0 +1,134 @@
+use decimal::{Decimal, DecimalAccumulator, DecimalError, RoundingMode};
+
+fn d8(input: &str) -> Decimal<i64, 8> {
+    input.parse().unwrap()
+}
+
+#[test]
+fn accumulate_sum_and_count() {
+    let mut acc = DecimalAccumulator::<i64, 8>::new();
+    assert!(acc.is_empty());
+    acc.add(d8("1.5"));
+    acc.add(d8("2.25"));
+    acc.sub(d8("0.75"));
+    assert_eq!(acc.count(), 1);
+    assert_eq!(acc.sum().unwrap().to_string(), "3.00000000");
+}
+
+#[test]
+fn sub_retracts_an_entry() {
+    let mut acc = DecimalAccumulator::<i64, 8>::new();
+    acc.add(d8("2"));
+    acc.add(d8("2"));
+    acc.sub(d8("2"));
+    assert_eq!(acc.count(), 1);
+    assert_eq!(
+        acc.mean(RoundingMode::HalfUp).unwrap().to_string(),
+        "2.00000000"
+    );
+
+    acc.sub(d8("2"));
+    assert!(acc.is_empty());
+    assert!(acc.sum().unwrap().is_zero());
+}
+
+#[test]
+#[should_panic(expected = "decimal accumulator is empty")]
+fn sub_from_empty_panics() {
+    let mut acc = DecimalAccumulator::<i64, 8>::new();
+    acc.sub(d8("1"));
+}
+
+#[test]
+fn accumulate_beyond_backing_range() {
+    let mut acc = DecimalAccumulator::<i64, 8>::new();
+    for _ in 0..4 {
+        acc.add(Decimal::MAX);
+    }
+    assert_eq!(acc.sum().unwrap_err(), DecimalError::Overflow);
+    assert_eq!(acc.mean(RoundingMode::Truncate).unwrap(), Decimal::MAX);
+
+    let mut acc = DecimalAccumulator::<i128, 2>::new();
+    acc.add(Decimal::MIN);
+    acc.add(Decimal::MIN);
+    assert_eq!(acc.mean(RoundingMode::HalfEven).unwrap(), Decimal::MIN);
+}
+
+#[test]
+fn accumulate_merge() {
+    let left: DecimalAccumulator<i64, 8> = [d8("1"), d8("2")].into_iter().collect();
+    let mut right = DecimalAccumulator::default();
+    right.extend([d8("3"), d8("4"), d8("5")]);
+
+    let mut total = left;
+    total.merge(right);
+    assert_eq!(total.count(), 5);
+    assert_eq!(total.sum().unwrap().to_string(), "15.00000000");
+    assert_eq!(
+        total.mean(RoundingMode::Truncate).unwrap().to_string(),
+        "3.00000000"
+    );
+}
+
+#[test]
+fn accumulate_mean_rounding() {
+    let acc: DecimalAccumulator<i64, 2> = ["0.01", "0.02", "0.02", "0.02"]
+        .iter()
+        .map(|s| s.parse::<Decimal<i64, 2>>().unwrap())
+        .collect();
+    // 0.07 / 4 = 0.0175
+    assert_eq!(
+        acc.mean(RoundingMode::Truncate).unwrap().to_string(),
+        "0.01"
+    );
+    assert_eq!(acc.mean(RoundingMode::HalfUp).unwrap().to_string(), "0.02");
+
+    let acc: DecimalAccumulator<i64, 2> = ["-0.01", "-0.02"]
+        .iter()
+        .map(|s| s.parse::<Decimal<i64, 2>>().unwrap())
+        .collect();
+    // -0.03 / 2 = -0.015
+    assert_eq!(acc.mean(RoundingMode::HalfUp).unwrap().to_string(), "-0.02");
+    assert_eq!(
+        acc.mean(RoundingMode::HalfEven).unwrap().to_string(),
+        "-0.02"
+    );
+    assert_eq!(
+        acc.mean(RoundingMode::Truncate).unwrap().to_string(),
+        "-0.01"
+    );
+}
+
+#[test]
+fn accumulate_mean_directed_rounding() {
+    let mean = |inputs: &[&str], mode| {
+        let acc: DecimalAccumulator<i64, 2> = inputs
+            .iter()
+            .map(|s| s.parse::<Decimal<i64, 2>>().unwrap())
+            .collect();
+        acc.mean(mode).unwrap().to_string()
+    };
+
+    // Exact means are left alone.
+    assert_eq!(mean(&["2.00", "2.00"], RoundingMode::Ceiling), "2.00");
+    assert_eq!(mean(&["2.00", "2.00"], RoundingMode::Floor), "2.00");
+    assert_eq!(mean(&["0.00"], RoundingMode::Ceiling), "0.00");
+    assert_eq!(mean(&["-2.00"], RoundingMode::Floor), "-2.00");
+    assert_eq!(mean(&["-2.00"], RoundingMode::Ceiling), "-2.00");
+
+    // 0.03 / 2 = 0.015 and -0.03 / 2 = -0.015
+    assert_eq!(mean(&["0.01", "0.02"], RoundingMode::Floor), "0.01");
+    assert_eq!(mean(&["0.01", "0.02"], RoundingMode::Ceiling), "0.02");
+    assert_eq!(mean(&["-0.01", "-0.02"], RoundingMode::Floor), "-0.02");
+    assert_eq!(mean(&["-0.01", "-0.02"], RoundingMode::Ceiling), "-0.01");
+}
+
+#[test]
+fn accumulate_empty_mean() {
+    let acc = DecimalAccumulator::<i64, 2>::new();
+    assert!(acc.sum().unwrap().is_zero());
+    assert_eq!(
+        acc.mean(RoundingMode::HalfUp).unwrap_err(),
+        DecimalError::DivisionByZero
+    );
+}
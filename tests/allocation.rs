@@ -0,0 +1,144 @@
+use decimal::{AllocationStrategy, Decimal, DecimalError};
+
+fn d2(input: &str) -> Decimal<i64, 2> {
+    input.parse().unwrap()
+}
+
+fn strings(parts: &[Decimal<i64, 2>]) -> Vec<String> {
+    parts.iter().map(ToString::to_string).collect()
+}
+
+fn total(parts: &[Decimal<i64, 2>]) -> Decimal<i64, 2> {
+    parts.iter().sum()
+}
+
+#[test]
+fn split_evenly_strategies() {
+    let amount = d2("100.00");
+    let parts = amount.split_evenly(3, AllocationStrategy::FirstN).unwrap();
+    assert_eq!(strings(&parts), ["33.34", "33.33", "33.33"]);
+    assert_eq!(total(&parts), amount);
+
+    let parts = amount
+        .split_evenly(3, AllocationStrategy::RoundRobin { start: 2 })
+        .unwrap();
+    assert_eq!(strings(&parts), ["33.33", "33.33", "33.34"]);
+
+    let parts = d2("0.05")
+        .split_evenly(3, AllocationStrategy::RoundRobin { start: 2 })
+        .unwrap();
+    assert_eq!(strings(&parts), ["0.02", "0.01", "0.02"]);
+}
+
+#[test]
+fn split_evenly_negative() {
+    let amount = d2("-10.00");
+    let parts = amount
+        .split_evenly(3, AllocationStrategy::LargestRemainder)
+        .unwrap();
+    assert_eq!(strings(&parts), ["-3.34", "-3.33", "-3.33"]);
+    assert_eq!(total(&parts), amount);
+}
+
+#[test]
+fn allocate_largest_remainder() {
+    let weights = ["0.50", "0.30", "0.20"].map(|w| w.parse::<Decimal<i64, 2>>().unwrap());
+    let parts = d2("0.07")
+        .allocate(&weights, AllocationStrategy::LargestRemainder)
+        .unwrap();
+    // Exact shares 0.035, 0.021, 0.014.
+    assert_eq!(strings(&parts), ["0.04", "0.02", "0.01"]);
+
+    let parts = d2("0.07")
+        .allocate(&weights, AllocationStrategy::FirstN)
+        .unwrap();
+    assert_eq!(strings(&parts), ["0.04", "0.02", "0.01"]);
+
+    let parts = d2("0.07")
+        .allocate(&weights, AllocationStrategy::RoundRobin { start: 1 })
+        .unwrap();
+    assert_eq!(strings(&parts), ["0.03", "0.03", "0.01"]);
+}
+
+#[test]
+fn allocate_zero_weights_get_nothing() {
+    let weights = [1, 0, 1, 0].map(|w| Decimal::<i64, 0>::try_from(w).unwrap());
+    for strategy in [
+        AllocationStrategy::LargestRemainder,
+        AllocationStrategy::FirstN,
+        AllocationStrategy::RoundRobin { start: 1 },
+        AllocationStrategy::Random { seed: 7 },
+    ] {
+        let parts = d2("1.01").allocate(&weights, strategy).unwrap();
+        assert!(parts[1].is_zero() && parts[3].is_zero());
+        assert_eq!(total(&parts), d2("1.01"));
+    }
+}
+
+#[test]
+fn allocate_random_is_seeded() {
+    let weights = [1; 7].map(|w| Decimal::<i64, 0>::try_from(w).unwrap());
+    let amount = d2("1.00");
+    let first = amount
+        .allocate(&weights, AllocationStrategy::Random { seed: 42 })
+        .unwrap();
+    let second = amount
+        .allocate(&weights, AllocationStrategy::Random { seed: 42 })
+        .unwrap();
+    assert_eq!(first, second);
+    assert_eq!(total(&first), amount);
+    assert_eq!(first.iter().filter(|part| **part == d2("0.15")).count(), 2);
+}
+
+#[test]
+fn allocate_invalid_weights() {
+    let amount = d2("1.00");
+    let empty: [Decimal<i64, 0>; 0] = [];
+    assert_eq!(
+        amount
+            .allocate(&empty, AllocationStrategy::FirstN)
+            .unwrap_err(),
+        DecimalError::Empty
+    );
+
+    let zeros = [Decimal::<i64, 0>::zero(); 2];
+    assert_eq!(
+        amount
+            .allocate(&zeros, AllocationStrategy::FirstN)
+            .unwrap_err(),
+        DecimalError::DivisionByZero
+    );
+
+    let negative = [d2("1.00"), d2("-0.50")];
+    assert_eq!(
+        amount
+            .allocate(&negative, AllocationStrategy::FirstN)
+            .unwrap_err(),
+        DecimalError::Invalid
+    );
+
+    assert_eq!(
+        amount
+            .split_evenly(0, AllocationStrategy::FirstN)
+            .unwrap_err(),
+        DecimalError::DivisionByZero
+    );
+
+    // The part count is bounded the same way for every amount.
+    let parts = d2("0.05")
+        .split_evenly(1 << 20, AllocationStrategy::FirstN)
+        .unwrap();
+    assert_eq!(total(&parts), d2("0.05"));
+    assert_eq!(
+        d2("0.05")
+            .split_evenly((1 << 20) + 1, AllocationStrategy::FirstN)
+            .unwrap_err(),
+        DecimalError::Invalid
+    );
+    let wide = Decimal::<i128, 2>::from_minor_units(i128::MAX);
+    assert_eq!(
+        wide.split_evenly(usize::MAX, AllocationStrategy::FirstN)
+            .unwrap_err(),
+        DecimalError::Invalid
+    );
+}
@@ -169,6 +169,37 @@ fn divide_rounding_modes() {
     assert_eq!(half_even.to_string(), "0.12");
 }
 
+#[test]
+fn directed_rounding_follows_sign() {
+    let amount = "-1.00".parse::<Decimal<i64, 2>>().unwrap();
+    let rate = "0.3333".parse::<Decimal<i64, 4>>().unwrap();
+
+    let floor = amount
+        .mul_rescale::<4, 2>(rate, RoundingMode::Floor)
+        .unwrap();
+    assert_eq!(floor.to_string(), "-0.34");
+    let ceiling = amount
+        .mul_rescale::<4, 2>(rate, RoundingMode::Ceiling)
+        .unwrap();
+    assert_eq!(ceiling.to_string(), "-0.33");
+
+    let three = "3.0000".parse::<Decimal<i64, 4>>().unwrap();
+    let floor = amount
+        .div_rescale::<4, 2>(three, RoundingMode::Floor)
+        .unwrap();
+    assert_eq!(floor.to_string(), "-0.34");
+    let ceiling = amount
+        .div_rescale::<4, 2>(three, RoundingMode::Ceiling)
+        .unwrap();
+    assert_eq!(ceiling.to_string(), "-0.33");
+
+    let positive = "1.00".parse::<Decimal<i64, 2>>().unwrap();
+    let ceiling = positive
+        .div_rescale::<4, 2>(three, RoundingMode::Ceiling)
+        .unwrap();
+    assert_eq!(ceiling.to_string(), "0.34");
+}
+
 #[test]
 fn divide_by_zero() {
     let amount = "10.00".parse::<Decimal<i64, 2>>().unwrap();
@@ -252,3 +283,25 @@ fn divide_half_even_tie() {
         .unwrap();
     assert_eq!(value.to_string(), "0.12");
 }
+
+#[test]
+fn multiply_exact_typed_scale() {
+    let amount = "10.25".parse::<Decimal<i64, 2>>().unwrap();
+    let rate = "0.0125".parse::<Decimal<i64, 4>>().unwrap();
+    let value: Decimal<i64, 6> = amount.mul_exact(rate).unwrap();
+    assert_eq!(value.to_string(), "0.128125");
+
+    let rounded = value.rescale::<2>(RoundingMode::HalfUp).unwrap();
+    assert_eq!(rounded.to_string(), "0.13");
+
+    let negative = "-1.5".parse::<Decimal<i128, 1>>().unwrap();
+    let value: Decimal<i128, 2> = negative.mul_exact(negative).unwrap();
+    assert_eq!(value.to_string(), "2.25");
+}
+
+#[test]
+fn multiply_exact_overflow() {
+    let max = "92233720368547758.07".parse::<Decimal<i64, 2>>().unwrap();
+    let two = "2".parse::<Decimal<i64, 0>>().unwrap();
+    assert_eq!(max.mul_exact(two).unwrap_err(), DecimalError::Overflow);
+}
@@ -0,0 +1,110 @@
+#![cfg(feature = "arrow")]
+
+use arrow_array::{Decimal128Array, Decimal256Array};
+use arrow_buffer::i256;
+use decimal::{Decimal, DecimalError, RoundingMode};
+
+fn d4(input: &str) -> Decimal<i128, 4> {
+    input.parse().unwrap()
+}
+
+#[test]
+fn zero_copy_view() {
+    let array = Decimal128Array::from(vec![12_345, -500, 0])
+        .with_precision_and_scale(10, 4)
+        .unwrap();
+    let values = Decimal::<i128, 4>::slice_from_arrow(&array).unwrap();
+    assert_eq!(values, [d4("1.2345"), d4("-0.05"), d4("0")]);
+    assert_eq!(
+        values.as_ptr().cast::<i128>(),
+        array.values().as_ptr(),
+        "view must borrow the Arrow buffer"
+    );
+
+    assert_eq!(
+        Decimal::<i128, 2>::slice_from_arrow(&array),
+        Err(DecimalError::ScaleMismatch {
+            expected: 2,
+            found: 4
+        })
+    );
+    let nullable = Decimal128Array::from(vec![Some(1), None])
+        .with_precision_and_scale(10, 4)
+        .unwrap();
+    assert_eq!(
+        Decimal::<i128, 4>::slice_from_arrow(&nullable),
+        Err(DecimalError::Invalid)
+    );
+}
+
+#[test]
+fn builds_arrays() {
+    let values = vec![d4("1.2345"), d4("-99.9999")];
+    let array = Decimal::into_arrow(values.clone(), 6).unwrap();
+    assert_eq!((array.precision(), array.scale()), (6, 4));
+    assert_eq!(array.value_as_string(1), "-99.9999");
+
+    let narrow: Vec<Decimal<i64, 2>> = vec!["12.50".parse().unwrap()];
+    let array = Decimal::to_arrow128(&narrow, 5).unwrap();
+    assert_eq!(array.value(0), 1_250);
+    let array = Decimal::to_arrow256(&narrow, 40).unwrap();
+    assert_eq!((array.precision(), array.scale()), (40, 2));
+    assert_eq!(array.value(0), i256::from_i128(1_250));
+
+    // Six digits do not fit precision 5.
+    assert_eq!(
+        Decimal::into_arrow(values.clone(), 5),
+        Err(DecimalError::Overflow)
+    );
+    // Precision must cover the scale and stay within Decimal128's 38 digits.
+    assert_eq!(
+        Decimal::into_arrow(values.clone(), 3),
+        Err(DecimalError::Invalid)
+    );
+    assert_eq!(
+        Decimal::to_arrow128(&narrow, 39),
+        Err(DecimalError::Invalid)
+    );
+}
+
+#[test]
+fn copies_with_rescaling() {
+    let array = Decimal128Array::from(vec![Some(12_345), None, Some(-12_355)])
+        .with_precision_and_scale(10, 4)
+        .unwrap();
+
+    let exact = Decimal::<i64, 6>::from_arrow128(&array, RoundingMode::HalfUp).unwrap();
+    assert_eq!(exact[0].unwrap().to_string(), "1.234500");
+    assert_eq!(exact[1], None);
+
+    let rounded = Decimal::<i64, 2>::from_arrow128(&array, RoundingMode::HalfEven).unwrap();
+    assert_eq!(rounded[0].unwrap().to_string(), "1.23");
+    assert_eq!(rounded[2].unwrap().to_string(), "-1.24");
+
+    let wide = Decimal256Array::from(vec![i256::from_i128(-12_345), i256::MAX])
+        .with_precision_and_scale(76, 4)
+        .unwrap();
+    assert_eq!(
+        Decimal::<i64, 2>::from_arrow256(&wide, RoundingMode::HalfUp),
+        Err(DecimalError::Overflow)
+    );
+    let wide = wide.slice(0, 1);
+    let values = Decimal::<i64, 3>::from_arrow256(&wide, RoundingMode::Floor).unwrap();
+    assert_eq!(values[0].unwrap().to_string(), "-1.235");
+
+    // A value wider than i128 that shrinks to fit once rescaled.
+    let huge = i256::from_i128(i128::MAX).wrapping_mul(i256::from_i128(100));
+    let wide = Decimal256Array::from(vec![huge])
+        .with_precision_and_scale(76, 20)
+        .unwrap();
+    let values = Decimal::<i128, 18>::from_arrow256(&wide, RoundingMode::Truncate).unwrap();
+    assert_eq!(values[0], Some(Decimal::<i128, 18>::MAX));
+
+    let overflow = Decimal128Array::from(vec![i128::MAX])
+        .with_precision_and_scale(38, 0)
+        .unwrap();
+    assert_eq!(
+        Decimal::<i64, 0>::from_arrow128(&overflow, RoundingMode::HalfUp),
+        Err(DecimalError::Overflow)
+    );
+}
@@ -0,0 +1,69 @@
+//! Edge cases of the internal multi-limb integers, reached through exact
+//! products: `Decimal::product` multiplies every factor before dividing once
+//! by `10^(SCALE·(n-1))`.
+
+use decimal::{Decimal, RoundingMode};
+
+fn d18(minor_units: i128) -> Decimal<i128, 18> {
+    Decimal::from_minor_units(minor_units)
+}
+
+#[test]
+fn division_adds_back_an_overestimated_digit() {
+    // The two-limb quotient estimate for `a·b / 10^36` is one too large here,
+    // so long division has to add the divisor back once.
+    let factors = [
+        d18(1_164_609_893_301_732_561),
+        d18(2_339_473_243_933_799_166_788_356_539),
+        d18(1),
+    ];
+    let truncated = Decimal::product(factors, RoundingMode::Truncate).unwrap();
+    assert_eq!(truncated.minor_units(), 2_724_573_684);
+    let rounded = Decimal::product(factors, RoundingMode::HalfUp).unwrap();
+    assert_eq!(rounded.minor_units(), 2_724_573_685);
+}
+
+#[test]
+fn multiplication_carries_across_full_limbs() {
+    // (2^127 - 1)^2 has every limb saturated before the 10^54 division.
+    let factors = [d18(i128::MAX), d18(i128::MAX), d18(1), d18(1)];
+    let truncated = Decimal::product(factors, RoundingMode::Truncate).unwrap();
+    assert_eq!(truncated.to_string(), "28948.022309329048855892");
+    let rounded = Decimal::product(factors, RoundingMode::HalfEven).unwrap();
+    assert_eq!(rounded.to_string(), "28948.022309329048855893");
+
+    let negative = [d18(i128::MIN + 1), d18(i128::MAX), d18(1), d18(1)];
+    let floor = Decimal::product(negative, RoundingMode::Floor).unwrap();
+    assert_eq!(floor.to_string(), "-28948.022309329048855893");
+}
+
+#[test]
+fn powers_of_ten_across_limb_boundaries() {
+    // Divisors of 10^9, 10^27 and 10^54 straddle the nine-digit steps used
+    // to build powers of ten.
+    let nines = Decimal::<i64, 9>::from_minor_units(999_999_999);
+    let square = Decimal::product([nines; 2], RoundingMode::HalfUp).unwrap();
+    assert_eq!(square.to_string(), "0.999999998");
+    let fourth = Decimal::product([nines; 4], RoundingMode::Truncate).unwrap();
+    assert_eq!(fourth.to_string(), "0.999999996");
+    let fourth = Decimal::product([nines; 4], RoundingMode::Ceiling).unwrap();
+    assert_eq!(fourth.to_string(), "0.999999997");
+
+    let one = d18(1_000_000_000_000_000_000);
+    let product = Decimal::product([d18(7); 4].into_iter().chain([one]), RoundingMode::Ceiling);
+    assert_eq!(product.unwrap().minor_units(), 1);
+}
+
+#[test]
+fn remainder_ties_span_several_limbs() {
+    // 0.5 · 3e-18 · 1 and 0.5 · 5e-18 · 1 leave a remainder of exactly half
+    // of 10^36.
+    let half = d18(500_000_000_000_000_000);
+    let one = d18(1_000_000_000_000_000_000);
+    let odd = Decimal::product([half, d18(3), one], RoundingMode::HalfEven).unwrap();
+    assert_eq!(odd.minor_units(), 2);
+    let even = Decimal::product([half, d18(5), one], RoundingMode::HalfEven).unwrap();
+    assert_eq!(even.minor_units(), 2);
+    let up = Decimal::product([half, d18(5), one], RoundingMode::HalfUp).unwrap();
+    assert_eq!(up.minor_units(), 3);
+}
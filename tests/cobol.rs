@@ -0,0 +1,245 @@
+use decimal::cobol::{PicField, ZonedCharset};
+use decimal::{Decimal, DecimalError};
+
+fn d2(input: &str) -> Decimal<i64, 2> {
+    input.parse().unwrap()
+}
+
+fn d4(input: &str) -> Decimal<i128, 4> {
+    input.parse().unwrap()
+}
+
+#[test]
+fn field_definitions() {
+    // PIC S9(5)V99
+    let field = PicField::new(7, 2, true).unwrap();
+    assert_eq!(
+        (field.digits(), field.scale(), field.signed()),
+        (7, 2, true)
+    );
+    assert_eq!(field.packed_len(), 4);
+    assert_eq!(field.zoned_len(), 7);
+    assert_eq!(PicField::new(6, 0, false).unwrap().packed_len(), 4);
+
+    assert_eq!(PicField::new(0, 0, true), Err(DecimalError::Invalid));
+    assert_eq!(PicField::new(39, 0, true), Err(DecimalError::Invalid));
+    assert_eq!(PicField::new(3, 4, true), Err(DecimalError::Invalid));
+}
+
+#[test]
+fn packed_fixtures() {
+    // PIC S9(5)V99 COMP-3
+    let field = PicField::new(7, 2, true).unwrap();
+    let cases: [(&str, &[u8]); 4] = [
+        ("12345.67", &[0x12, 0x34, 0x56, 0x7C]),
+        ("-12345.67", &[0x12, 0x34, 0x56, 0x7D]),
+        ("0", &[0x00, 0x00, 0x00, 0x0C]),
+        ("-0.05", &[0x00, 0x00, 0x00, 0x5D]),
+    ];
+    for (text, bytes) in cases {
+        assert_eq!(field.encode_packed(d2(text)).unwrap(), bytes, "{text}");
+        assert_eq!(field.decode_packed(bytes), Ok(d2(text)), "{text}");
+    }
+
+    // PIC S9(3)V9 COMP-3: an even digit count has a pad nibble.
+    let field = PicField::new(4, 1, true).unwrap();
+    assert_eq!(
+        field.encode_packed(d2("-1.50")).unwrap(),
+        [0x00, 0x01, 0x5D]
+    );
+    assert_eq!(field.decode_packed(&[0x09, 0x99, 0x9C]), Ok(d2("999.9")));
+
+    // PIC 9(3) COMP-3
+    let field = PicField::new(3, 0, false).unwrap();
+    assert_eq!(field.encode_packed(d2("42")).unwrap(), [0x04, 0x2F]);
+    assert_eq!(field.decode_packed(&[0x04, 0x2C]), Ok(d2("42")));
+
+    // Alternate sign nibbles.
+    let field = PicField::new(3, 0, true).unwrap();
+    assert_eq!(field.decode_packed(&[0x12, 0x3F]), Ok(d2("123")));
+    assert_eq!(field.decode_packed(&[0x12, 0x3A]), Ok(d2("123")));
+    assert_eq!(field.decode_packed(&[0x12, 0x3B]), Ok(d2("-123")));
+}
+
+#[test]
+fn packed_rescaling() {
+    // PIC S9(7)V9(4) read into two decimals.
+    let field = PicField::new(11, 4, true).unwrap();
+    let bytes = field.encode_packed(d2("-1234.5")).unwrap();
+    assert_eq!(bytes, [0x00, 0x01, 0x23, 0x45, 0x00, 0x0D]);
+    assert_eq!(field.decode_packed(&bytes), Ok(d2("-1234.5")));
+    assert_eq!(
+        field.decode_packed::<i64, 2>(&[0x00, 0x01, 0x23, 0x45, 0x67, 0x8C]),
+        Err(DecimalError::TooManyFractionalDigits {
+            provided: 4,
+            allowed: 2
+        })
+    );
+
+    // Wider than the field or than the target.
+    let field = PicField::new(5, 2, true).unwrap();
+    assert_eq!(
+        field.encode_packed(d4("0.125")),
+        Err(DecimalError::TooManyFractionalDigits {
+            provided: 3,
+            allowed: 2
+        })
+    );
+    assert_eq!(field.encode_packed(d2("1000")), Err(DecimalError::Overflow));
+    let unsigned = PicField::new(5, 2, false).unwrap();
+    assert_eq!(
+        unsigned.encode_packed(d2("-1")),
+        Err(DecimalError::Overflow)
+    );
+
+    let wide = PicField::new(38, 0, true).unwrap();
+    let bytes = wide
+        .encode_packed(d4("123456789012345678901234567890"))
+        .unwrap();
+    assert_eq!(
+        wide.decode_packed(&bytes),
+        Ok(d4("123456789012345678901234567890"))
+    );
+    let all_nines = [[0x09].as_slice(), &[0x99; 18], &[0x9C]].concat();
+    assert_eq!(
+        wide.decode_packed::<i64, 2>(&all_nines),
+        Err(DecimalError::Overflow)
+    );
+}
+
+#[test]
+fn packed_malformed() {
+    let field = PicField::new(7, 2, true).unwrap();
+    assert_eq!(
+        field.decode_packed::<i64, 2>(&[0x12, 0x3A, 0x56, 0x7C]),
+        Err(DecimalError::MalformedDigit { offset: 1 })
+    );
+    assert_eq!(
+        field.decode_packed::<i64, 2>(&[0x12, 0x34, 0xF6, 0x7C]),
+        Err(DecimalError::MalformedDigit { offset: 2 })
+    );
+    assert_eq!(
+        field.decode_packed::<i64, 2>(&[0x12, 0x34, 0x56, 0x77]),
+        Err(DecimalError::MalformedDigit { offset: 3 })
+    );
+    assert_eq!(
+        field.decode_packed::<i64, 2>(&[0x12, 0x34, 0x56]),
+        Err(DecimalError::Truncated)
+    );
+    assert_eq!(
+        field.decode_packed::<i64, 2>(&[0x12, 0x34, 0x56, 0x7C, 0x00]),
+        Err(DecimalError::Invalid)
+    );
+
+    // Non-zero pad nibble.
+    let even = PicField::new(4, 0, true).unwrap();
+    assert_eq!(
+        even.decode_packed::<i64, 2>(&[0x10, 0x00, 0x0C]),
+        Err(DecimalError::MalformedDigit { offset: 0 })
+    );
+
+    // Negative sign in an unsigned field.
+    let unsigned = PicField::new(3, 0, false).unwrap();
+    assert_eq!(
+        unsigned.decode_packed::<i64, 2>(&[0x12, 0x3D]),
+        Err(DecimalError::MalformedDigit { offset: 1 })
+    );
+}
+
+#[test]
+fn zoned_fixtures() {
+    // PIC S9(3)V99
+    let field = PicField::new(5, 2, true).unwrap();
+    let cases: [(&str, &[u8], &[u8]); 4] = [
+        ("-1.23", &[0xF0, 0xF0, 0xF1, 0xF2, 0xD3], b"0012L"),
+        ("1.23", &[0xF0, 0xF0, 0xF1, 0xF2, 0xC3], b"0012C"),
+        ("-100", &[0xF1, 0xF0, 0xF0, 0xF0, 0xD0], b"1000}"),
+        ("100", &[0xF1, 0xF0, 0xF0, 0xF0, 0xC0], b"1000{"),
+    ];
+    for (text, ebcdic, ascii) in cases {
+        let value = d2(text);
+        assert_eq!(
+            field.encode_zoned(value, ZonedCharset::Ebcdic).unwrap(),
+            ebcdic
+        );
+        assert_eq!(
+            field.encode_zoned(value, ZonedCharset::Ascii).unwrap(),
+            ascii
+        );
+        assert_eq!(field.decode_zoned(ebcdic, ZonedCharset::Ebcdic), Ok(value));
+        assert_eq!(field.decode_zoned(ascii, ZonedCharset::Ascii), Ok(value));
+    }
+
+    // Unsigned last bytes in a signed field read as positive.
+    assert_eq!(
+        field.decode_zoned(&[0xF0, 0xF0, 0xF1, 0xF2, 0xF3], ZonedCharset::Ebcdic),
+        Ok(d2("1.23"))
+    );
+    assert_eq!(
+        field.decode_zoned(b"00123", ZonedCharset::Ascii),
+        Ok(d2("1.23"))
+    );
+
+    // PIC 9(4)
+    let unsigned = PicField::new(4, 0, false).unwrap();
+    assert_eq!(
+        unsigned
+            .encode_zoned(d2("42"), ZonedCharset::Ebcdic)
+            .unwrap(),
+        [0xF0, 0xF0, 0xF4, 0xF2]
+    );
+    assert_eq!(
+        unsigned
+            .encode_zoned(d2("42"), ZonedCharset::Ascii)
+            .unwrap(),
+        b"0042"
+    );
+    assert_eq!(
+        unsigned.decode_zoned(b"0042", ZonedCharset::Ascii),
+        Ok(d2("42"))
+    );
+}
+
+#[test]
+fn zoned_malformed() {
+    let field = PicField::new(5, 2, true).unwrap();
+    assert_eq!(
+        field.decode_zoned::<i64, 2>(&[0xF0, 0xC0, 0xF1, 0xF2, 0xD3], ZonedCharset::Ebcdic),
+        Err(DecimalError::MalformedDigit { offset: 1 })
+    );
+    assert_eq!(
+        field.decode_zoned::<i64, 2>(&[0xF0, 0xF0, 0xF1, 0xFA, 0xD3], ZonedCharset::Ebcdic),
+        Err(DecimalError::MalformedDigit { offset: 3 })
+    );
+    assert_eq!(
+        field.decode_zoned::<i64, 2>(&[0xF0, 0xF0, 0xF1, 0xF2, 0xB3], ZonedCharset::Ebcdic),
+        Err(DecimalError::MalformedDigit { offset: 4 })
+    );
+    assert_eq!(
+        field.decode_zoned::<i64, 2>(b"0 12C", ZonedCharset::Ascii),
+        Err(DecimalError::MalformedDigit { offset: 1 })
+    );
+    assert_eq!(
+        field.decode_zoned::<i64, 2>(b"00L23", ZonedCharset::Ascii),
+        Err(DecimalError::MalformedDigit { offset: 2 })
+    );
+    assert_eq!(
+        field.decode_zoned::<i64, 2>(b"0012S", ZonedCharset::Ascii),
+        Err(DecimalError::MalformedDigit { offset: 4 })
+    );
+    assert_eq!(
+        field.decode_zoned::<i64, 2>(b"0012", ZonedCharset::Ascii),
+        Err(DecimalError::Truncated)
+    );
+
+    // Overpunch in an unsigned field.
+    let unsigned = PicField::new(4, 0, false).unwrap();
+    assert_eq!(
+        unsigned.decode_zoned::<i64, 2>(b"004B", ZonedCharset::Ascii),
+        Err(DecimalError::MalformedDigit { offset: 3 })
+    );
+    assert_eq!(
+        unsigned.decode_zoned::<i64, 2>(&[0xF0, 0xF0, 0xF4, 0xC2], ZonedCharset::Ebcdic),
+        Err(DecimalError::MalformedDigit { offset: 3 })
+    );
+}
@@ -0,0 +1,142 @@
+use decimal::columns::{ColumnError, ColumnLayout, ColumnReader};
+use decimal::{Decimal, DecimalError, ParseOptions, RoundingMode};
+
+fn d2(input: &str) -> Decimal<i64, 2> {
+    input.parse().unwrap()
+}
+
+const CSV: &[u8] = b"date,description,amount\r\n\
+2024-01-02,Coffee,-3.50\r\n\
+2024-01-03,\"Rent, January\",-1200.00\r\n\
+2024-01-04,Salary,2500\r\n\
+2024-01-05,Typo,12.3.4\r\n\
+2024-01-06,Short\r\n";
+
+#[test]
+fn csv_column() {
+    let layout = ColumnLayout::Delimited {
+        delimiter: b',',
+        index: 2,
+    };
+    let mut reader = ColumnReader::<i64, 2>::new(CSV, layout).skip_lines(1);
+    let rows: Vec<_> = reader.by_ref().collect();
+    assert_eq!(
+        rows,
+        [
+            Ok(d2("-3.50")),
+            Ok(d2("-1200")),
+            Ok(d2("2500")),
+            Err(ColumnError {
+                line: 5,
+                column: 17,
+                error: DecimalError::Invalid
+            }),
+            Err(ColumnError {
+                line: 6,
+                column: 17,
+                error: DecimalError::Truncated
+            }),
+        ]
+    );
+
+    let stats = reader.stats();
+    assert_eq!((stats.rows(), stats.errors()), (5, 2));
+    assert_eq!(stats.values().count(), 3);
+    assert_eq!(stats.values().sum(), Ok(d2("1296.50")));
+    assert_eq!(stats.min(), Some(d2("-1200")));
+    assert_eq!(stats.max(), Some(d2("2500")));
+}
+
+#[test]
+fn quoted_fields() {
+    let input = b"\"a,\"\"b\"\"\",\"1,234.50\"\n\"x\",\"7\"";
+    let layout = ColumnLayout::Delimited {
+        delimiter: b',',
+        index: 1,
+    };
+    let options = ParseOptions {
+        group_separator: Some(b','),
+        ..ParseOptions::default()
+    };
+    let rows: Vec<_> = ColumnReader::<i64, 2>::with_options(input, layout, options).collect();
+    assert_eq!(rows, [Ok(d2("1234.50")), Ok(d2("7"))]);
+
+    // The strict grammar has no group separator.
+    let rows: Vec<_> = ColumnReader::<i64, 2>::new(input, layout).collect();
+    assert_eq!(
+        rows[0],
+        Err(ColumnError {
+            line: 1,
+            column: 12,
+            error: DecimalError::Invalid
+        })
+    );
+}
+
+#[test]
+fn fixed_width_column() {
+    // Account in 0..6, amount right-aligned in 6..18.
+    let input = b"ACC001     1,234.50 EUR\n\
+ACC002      (250.00) EUR\n\
+ACC003         0.125 EUR\n\
+ACC004              \n\
+ACC0\n";
+    let layout = ColumnLayout::FixedWidth {
+        start: 6,
+        width: 14,
+    };
+    let options = ParseOptions {
+        group_separator: Some(b','),
+        trim_whitespace: true,
+        parentheses_negative: true,
+        rounding: Some(RoundingMode::HalfEven),
+        ..ParseOptions::default()
+    };
+    let rows: Vec<_> = ColumnReader::<i64, 2>::with_options(input, layout, options).collect();
+    assert_eq!(
+        rows,
+        [
+            Ok(d2("1234.50")),
+            Ok(d2("-250")),
+            Ok(d2("0.12")),
+            Err(ColumnError {
+                line: 4,
+                column: 7,
+                error: DecimalError::Empty
+            }),
+            Err(ColumnError {
+                line: 5,
+                column: 5,
+                error: DecimalError::Truncated
+            }),
+        ]
+    );
+}
+
+#[test]
+fn finish_and_errors() {
+    let input = b"1.00\n\n2.00\n-4.50";
+    let layout = ColumnLayout::FixedWidth {
+        start: 0,
+        width: 10,
+    };
+    let mut reader = ColumnReader::<i64, 2>::new(input, layout);
+    assert_eq!(reader.next(), Some(Ok(d2("1"))));
+
+    let stats = reader.finish();
+    assert_eq!((stats.rows(), stats.errors()), (4, 1));
+    assert_eq!(stats.values().mean(RoundingMode::HalfUp), Ok(d2("-0.50")));
+    assert_eq!(stats.min(), Some(d2("-4.50")));
+
+    let empty = ColumnReader::<i64, 2>::new(b"", layout).finish();
+    assert_eq!((empty.rows(), empty.min()), (0, None));
+
+    let error = ColumnError {
+        line: 3,
+        column: 9,
+        error: DecimalError::Invalid,
+    };
+    assert_eq!(error.to_string(), "line 3, column 9: invalid format");
+    let source = std::error::Error::source(&error).unwrap();
+    assert_eq!(source.to_string(), "invalid format");
+}
@@ -0,0 +1,107 @@
+use std::cmp::Ordering;
+
+use decimal::{Decimal, RoundingMode};
+
+const FEE: Decimal<i64, 2> = Decimal::from_minor_units(125);
+const LIMIT: Decimal<i64, 2> = Decimal::<i64, 2>::from_integer(1_000).unwrap();
+const FEE_TOTAL: Decimal<i64, 2> = FEE.const_checked_add(FEE).unwrap();
+const LIMIT_NET: Decimal<i64, 2> = LIMIT.const_checked_sub(FEE).unwrap();
+const FEE_ROUNDED: Decimal<i64, 1> = FEE.const_rescale::<1>(RoundingMode::HalfEven).unwrap();
+const RATE_CAP: Decimal<i128, 4> = Decimal::<i128, 4>::const_parse("-12.5").unwrap();
+const FEE_SCHEDULE: [Decimal<i128, 4>; 3] = [Decimal::zero(), Decimal::EPSILON, Decimal::one()];
+
+#[test]
+fn const_constructors() {
+    assert_eq!(FEE.to_string(), "1.25");
+    assert_eq!(LIMIT.to_string(), "1000.00");
+    assert_eq!(FEE.minor_units(), 125);
+    assert_eq!(FEE_SCHEDULE[0].to_string(), "0.0000");
+    assert_eq!(FEE_SCHEDULE[1].to_string(), "0.0001");
+    assert_eq!(FEE_SCHEDULE[2].to_string(), "1.0000");
+    assert!(Decimal::<i64, 18>::from_integer(10).is_none());
+}
+
+#[test]
+fn const_arithmetic() {
+    assert_eq!(FEE_TOTAL.to_string(), "2.50");
+    assert_eq!(LIMIT_NET.to_string(), "998.75");
+    assert_eq!(FEE_ROUNDED.to_string(), "1.2");
+    assert!(
+        Decimal::<i64, 2>::MAX
+            .const_checked_add(Decimal::EPSILON)
+            .is_none()
+    );
+    assert!(
+        Decimal::<i64, 2>::MIN
+            .const_checked_sub(Decimal::EPSILON)
+            .is_none()
+    );
+}
+
+#[test]
+fn const_rescale_matches_rescale() {
+    let value = "-1.235".parse::<Decimal<i64, 3>>().unwrap();
+    for mode in [
+        RoundingMode::Truncate,
+        RoundingMode::HalfUp,
+        RoundingMode::HalfEven,
+    ] {
+        assert_eq!(
+            value.const_rescale::<2>(mode),
+            value.rescale::<2>(mode).ok()
+        );
+    }
+    assert_eq!(
+        value
+            .const_rescale::<5>(RoundingMode::Truncate)
+            .unwrap()
+            .to_string(),
+        "-1.23500"
+    );
+    assert!(
+        Decimal::<i64, 0>::MAX
+            .const_rescale::<1>(RoundingMode::Truncate)
+            .is_none()
+    );
+}
+
+#[test]
+fn const_comparisons() {
+    assert_eq!(FEE.const_cmp(LIMIT), Ordering::Less);
+    assert_eq!(LIMIT.const_cmp(FEE), Ordering::Greater);
+    assert!(FEE.const_eq(Decimal::from_minor_units(125)));
+    assert!(FEE < LIMIT);
+    assert_eq!(FEE.max(LIMIT), LIMIT);
+}
+
+#[test]
+fn const_parse_matches_from_str() {
+    assert_eq!(RATE_CAP.to_string(), "-12.5000");
+    for input in [
+        "1.25",
+        "-0.5",
+        "+7",
+        ".5",
+        "",
+        "1.234",
+        "1,000",
+        " 1",
+        "1.2.3",
+        "92233720368547758.08",
+        "-92233720368547758.08",
+    ] {
+        assert_eq!(
+            Decimal::<i64, 2>::const_parse(input),
+            input.parse::<Decimal<i64, 2>>().ok(),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn min_max_epsilon() {
+    assert_eq!(Decimal::<i64, 2>::MAX.minor_units(), i64::MAX);
+    assert_eq!(Decimal::<i64, 2>::MIN.minor_units(), i64::MIN);
+    assert_eq!(Decimal::<i128, 6>::EPSILON.to_string(), "0.000001");
+    assert_eq!(Decimal::<i64, 0>::one().to_string(), "1");
+}
@@ -99,6 +99,26 @@ fn rescale_half_even() {
     assert_eq!(down.to_string(), "-1.24");
 }
 
+#[test]
+fn rescale_floor_and_ceiling() {
+    let value = "1.231".parse::<Decimal<i64, 3>>().unwrap();
+    let floor = value.rescale::<2>(RoundingMode::Floor).unwrap();
+    let ceiling = value.rescale::<2>(RoundingMode::Ceiling).unwrap();
+    assert_eq!(floor.to_string(), "1.23");
+    assert_eq!(ceiling.to_string(), "1.24");
+
+    let value = "-1.231".parse::<Decimal<i64, 3>>().unwrap();
+    let floor = value.rescale::<2>(RoundingMode::Floor).unwrap();
+    let ceiling = value.rescale::<2>(RoundingMode::Ceiling).unwrap();
+    assert_eq!(floor.to_string(), "-1.24");
+    assert_eq!(ceiling.to_string(), "-1.23");
+
+    let value = Decimal::<i64, 2>::from_f64(-0.125, RoundingMode::Floor).unwrap();
+    assert_eq!(value.to_string(), "-0.13");
+    let value = Decimal::<i64, 2>::from_f64(0.121, RoundingMode::Ceiling).unwrap();
+    assert_eq!(value.to_string(), "0.13");
+}
+
 #[test]
 fn convert_to_f64() {
     let value = "1.25".parse::<Decimal<i64, 2>>().unwrap();
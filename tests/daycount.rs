@@ -0,0 +1,159 @@
+use decimal::daycount::{Date, DayCount};
+use decimal::{Decimal, DecimalError, RoundingMode};
+
+fn date(year: i32, month: u32, day: u32) -> Date {
+    Date::new(year, month, day).unwrap()
+}
+
+fn fraction(convention: DayCount, start: Date, end: Date) -> String {
+    let value: Decimal<i64, 8> = convention
+        .year_fraction(start, end, RoundingMode::HalfUp)
+        .unwrap();
+    value.to_string()
+}
+
+#[test]
+fn date_validation() {
+    assert!(Date::new(2024, 2, 29).is_ok());
+    assert_eq!(Date::new(2023, 2, 29), Err(DecimalError::Invalid));
+    assert_eq!(Date::new(1900, 2, 29), Err(DecimalError::Invalid));
+    assert_eq!(Date::new(2024, 13, 1), Err(DecimalError::Invalid));
+    assert_eq!(Date::new(2024, 4, 31), Err(DecimalError::Invalid));
+
+    assert_eq!(date(2000, 1, 1).days_until(date(2001, 1, 1)), 366);
+    assert_eq!(date(2024, 3, 1).days_until(date(2024, 2, 1)), -29);
+}
+
+#[test]
+fn actual_conventions() {
+    let (start, end) = (date(2007, 1, 15), date(2007, 7, 15));
+    assert_eq!(DayCount::Act360.ratio(start, end), Ok((181, 360)));
+    assert_eq!(fraction(DayCount::Act360, start, end), "0.50277778");
+    assert_eq!(fraction(DayCount::Act365Fixed, start, end), "0.49589041");
+}
+
+#[test]
+fn thirty_360_variants() {
+    let (start, end) = (date(2007, 2, 28), date(2007, 8, 31));
+    assert_eq!(DayCount::Thirty360Us.ratio(start, end), Ok((180, 360)));
+    assert_eq!(
+        DayCount::Thirty360European.ratio(start, end),
+        Ok((182, 360))
+    );
+
+    let isda = DayCount::Thirty360Isda {
+        termination: date(2008, 2, 29),
+    };
+    assert_eq!(isda.ratio(start, end), Ok((180, 360)));
+    // The February termination date keeps its actual day.
+    assert_eq!(isda.ratio(end, date(2008, 2, 29)), Ok((179, 360)));
+    assert_eq!(
+        DayCount::Thirty360European.ratio(end, date(2008, 2, 29)),
+        Ok((179, 360))
+    );
+
+    // US: day 31 is only cut back when the start is at month end.
+    let us = DayCount::Thirty360Us;
+    assert_eq!(
+        us.ratio(date(2007, 1, 15), date(2007, 3, 31)),
+        Ok((76, 360))
+    );
+    assert_eq!(
+        us.ratio(date(2007, 1, 31), date(2007, 3, 31)),
+        Ok((60, 360))
+    );
+}
+
+#[test]
+fn actual_actual() {
+    let (start, end) = (date(2003, 11, 1), date(2004, 5, 1));
+    // 61/365 + 121/366
+    assert_eq!(
+        DayCount::ActActIsda.ratio(start, end),
+        Ok((61 * 366 + 121 * 365, 365 * 366))
+    );
+    assert_eq!(fraction(DayCount::ActActIsda, start, end), "0.49772438");
+    assert_eq!(fraction(DayCount::ActActIsda, end, start), "-0.49772438");
+
+    let icma = DayCount::ActActIcma {
+        period_start: start,
+        period_end: end,
+        frequency: 2,
+    };
+    assert_eq!(fraction(icma, start, end), "0.50000000");
+    assert_eq!(icma.ratio(start, date(2004, 2, 1)), Ok((92, 364)));
+
+    let invalid = DayCount::ActActIcma {
+        period_start: start,
+        period_end: end,
+        frequency: 0,
+    };
+    assert_eq!(invalid.ratio(start, end), Err(DecimalError::Invalid));
+    // Accrual dates must fall within the coupon period.
+    assert_eq!(
+        icma.ratio(date(2003, 10, 31), end),
+        Err(DecimalError::Invalid)
+    );
+    assert_eq!(
+        icma.ratio(start, date(2004, 5, 2)),
+        Err(DecimalError::Invalid)
+    );
+
+    let last = date(i32::MAX, 12, 31);
+    assert_eq!(
+        DayCount::ActActIsda.ratio(date(i32::MAX, 12, 30), last),
+        Ok((366, 365 * 366))
+    );
+}
+
+#[test]
+fn actual_actual_isda_wide_ranges() {
+    let isda = DayCount::ActActIsda;
+    // 55115 days in common years and 17934 in leap years; 2000 is a leap year.
+    assert_eq!(
+        isda.ratio(date(1900, 3, 1), date(2100, 3, 1)),
+        Ok((55115 * 366 + 17934 * 365, 365 * 366))
+    );
+    // Whole years only, so the fraction is exactly the year count.
+    assert_eq!(
+        isda.ratio(date(-1_000_000_000, 1, 1), date(1_000_000_000, 1, 1)),
+        Ok((2_000_000_000 * 365 * 366, 365 * 366))
+    );
+    let (numerator, denominator) = isda
+        .ratio(date(i32::MIN, 1, 1), date(i32::MAX, 12, 31))
+        .unwrap();
+    assert_eq!(
+        isda.ratio(date(i32::MAX, 12, 31), date(i32::MIN, 1, 1)),
+        Ok((-numerator, denominator))
+    );
+}
+
+#[test]
+fn accrued_interest_rounds_once() {
+    let notional: Decimal<i64, 2> = "1000000.00".parse().unwrap();
+    let rate: Decimal<i64, 4> = "0.05".parse().unwrap();
+    let (start, end) = (date(2007, 1, 15), date(2007, 7, 15));
+
+    // 1,000,000 * 0.05 * 181 / 360 = 25138.8888...
+    let accrued = DayCount::Act360
+        .accrued_interest(notional, rate, start, end, RoundingMode::HalfUp)
+        .unwrap();
+    assert_eq!(accrued.to_string(), "25138.89");
+    let accrued = DayCount::Act360
+        .accrued_interest(notional, rate, start, end, RoundingMode::Truncate)
+        .unwrap();
+    assert_eq!(accrued.to_string(), "25138.88");
+
+    // 1,000,000 * 0.0425 * (61/365 + 121/366) = 21153.2861...
+    let rate: Decimal<i64, 4> = "0.0425".parse().unwrap();
+    let accrued = DayCount::ActActIsda
+        .accrued_interest(
+            notional,
+            rate,
+            date(2003, 11, 1),
+            date(2004, 5, 1),
+            RoundingMode::HalfEven,
+        )
+        .unwrap();
+    assert_eq!(accrued.to_string(), "21153.29");
+}
@@ -0,0 +1,220 @@
+use decimal::{Decimal, DecimalError};
+
+fn d2(input: &str) -> Decimal<i64, 2> {
+    input.parse().unwrap()
+}
+
+#[test]
+fn fixed_width_round_trip() {
+    let value = d2("-1234.56");
+    assert_eq!(value.to_le_bytes(), (-123_456_i64).to_le_bytes());
+    assert_eq!(value.to_be_bytes(), (-123_456_i64).to_be_bytes());
+    assert_eq!(Decimal::<i64, 2>::from_le_bytes(value.to_le_bytes()), value);
+    assert_eq!(Decimal::<i64, 2>::from_be_bytes(value.to_be_bytes()), value);
+
+    let wide = Decimal::<i128, 6>::MAX;
+    let bytes: [u8; 16] = wide.to_be_bytes();
+    assert_eq!(Decimal::<i128, 6>::from_be_bytes(bytes), wide);
+}
+
+#[test]
+fn compact_encoding() {
+    assert_eq!(d2("0").to_compact_bytes(), [2, 0]);
+    // -150 zigzags to 299 = 0b10_0101011.
+    assert_eq!(d2("-1.50").to_compact_bytes(), [2, 0xab, 0x02]);
+    assert_eq!(d2("0.01").to_compact_bytes(), [2, 0x02]);
+
+    for value in [
+        d2("0"),
+        d2("-0.01"),
+        d2("1234.56"),
+        Decimal::MIN,
+        Decimal::MAX,
+    ] {
+        let bytes = value.to_compact_bytes();
+        assert_eq!(Decimal::<i64, 2>::from_compact_bytes(&bytes), Ok(value));
+    }
+    for value in [Decimal::<i128, 18>::MIN, Decimal::<i128, 18>::MAX] {
+        let bytes = value.to_compact_bytes();
+        assert_eq!(bytes.len(), 20);
+        assert_eq!(Decimal::<i128, 18>::from_compact_bytes(&bytes), Ok(value));
+    }
+}
+
+#[test]
+fn compact_stream() {
+    let mut frame = Vec::new();
+    d2("1.25").encode_compact(&mut frame);
+    d2("-300.00").encode_compact(&mut frame);
+
+    let (first, read) = Decimal::<i64, 2>::decode_compact(&frame).unwrap();
+    assert_eq!(first, d2("1.25"));
+    let (second, second_read) = Decimal::<i64, 2>::decode_compact(&frame[read..]).unwrap();
+    assert_eq!(second, d2("-300.00"));
+    assert_eq!(read + second_read, frame.len());
+}
+
+#[test]
+fn compact_errors() {
+    let bytes = d2("-1.50").to_compact_bytes();
+    assert_eq!(
+        Decimal::<i64, 3>::from_compact_bytes(&bytes),
+        Err(DecimalError::ScaleMismatch {
+            expected: 3,
+            found: 2
+        })
+    );
+    assert_eq!(
+        Decimal::<i64, 2>::from_compact_bytes(&bytes[..2]),
+        Err(DecimalError::Truncated)
+    );
+    assert_eq!(
+        Decimal::<i64, 2>::from_compact_bytes(&[]),
+        Err(DecimalError::Truncated)
+    );
+    assert_eq!(
+        Decimal::<i64, 2>::from_compact_bytes(&[2, 0, 0]),
+        Err(DecimalError::Invalid)
+    );
+    // Non-minimal encodings of 0 and -1.50.
+    for padded in [&[2, 0x80, 0x00][..], &[2, 0xab, 0x82, 0x00]] {
+        assert_eq!(
+            Decimal::<i64, 2>::decode_compact(padded),
+            Err(DecimalError::Invalid)
+        );
+    }
+
+    let wide = Decimal::<i128, 2>::MAX.to_compact_bytes();
+    assert_eq!(
+        Decimal::<i64, 2>::from_compact_bytes(&wide),
+        Err(DecimalError::Overflow)
+    );
+    let mut too_long = vec![2];
+    too_long.extend([0xff; 19]);
+    too_long.push(0x01);
+    assert_eq!(
+        Decimal::<i64, 2>::from_compact_bytes(&too_long),
+        Err(DecimalError::Invalid)
+    );
+}
+
+/// SplitMix64, for reproducible pseudo-random values.
+fn next(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Edge cases followed by random values of mixed magnitude.
+fn samples(seed: u64) -> Vec<i128> {
+    let mut values = vec![
+        0,
+        1,
+        -1,
+        99,
+        -100,
+        i128::from(i64::MIN),
+        i128::from(i64::MAX),
+    ];
+    let mut state = seed;
+    for _ in 0..500 {
+        let raw = (u128::from(next(&mut state)) << 64) | u128::from(next(&mut state));
+        let bits = next(&mut state) % 126;
+        values.push((raw as i128) >> bits);
+    }
+    values
+}
+
+#[test]
+fn memcomparable_matches_ord() {
+    let values: Vec<Decimal<i64, 2>> = samples(1)
+        .into_iter()
+        .filter_map(|minor| i64::try_from(minor).ok())
+        .map(Decimal::from_minor_units)
+        .collect();
+    for a in &values {
+        let key = a.to_memcomparable();
+        assert_eq!(Decimal::<i64, 2>::from_memcomparable(key), *a);
+        for b in &values {
+            assert_eq!(key.cmp(&b.to_memcomparable()), a.cmp(b));
+        }
+    }
+
+    let wide: Vec<Decimal<i128, 6>> = samples(2)
+        .into_iter()
+        .chain([i128::MIN, i128::MAX])
+        .map(Decimal::from_minor_units)
+        .collect();
+    for a in &wide {
+        for b in &wide {
+            assert_eq!(a.to_memcomparable().cmp(&b.to_memcomparable()), a.cmp(b));
+        }
+    }
+}
+
+#[test]
+fn normalized_keys_sort_across_scales() {
+    let narrow: Vec<Decimal<i64, 2>> = samples(3)
+        .into_iter()
+        .filter_map(|minor| i64::try_from(minor).ok())
+        .map(Decimal::from_minor_units)
+        .collect();
+    let wide: Vec<Decimal<i128, 7>> = samples(4)
+        .into_iter()
+        .chain([i128::MIN, i128::MAX])
+        .map(Decimal::from_minor_units)
+        .collect();
+
+    for a in &narrow {
+        let key = a.to_memcomparable_normalized();
+        assert_eq!(
+            Decimal::<i64, 2>::from_memcomparable_normalized(&key),
+            Ok(*a)
+        );
+        // Compare numerically at scale 7 where both are exact.
+        let a_wide = Decimal::<i128, 2>::from(*a).try_rescale::<7>().unwrap();
+        for b in &wide {
+            let b_key = b.to_memcomparable_normalized();
+            assert_eq!(key.cmp(&b_key), a_wide.cmp(b));
+        }
+    }
+    for a in &wide {
+        let key = a.to_memcomparable_normalized();
+        assert_eq!(
+            Decimal::<i128, 7>::from_memcomparable_normalized(&key),
+            Ok(*a)
+        );
+    }
+}
+
+#[test]
+fn normalized_key_errors() {
+    let value = "-1.5".parse::<Decimal<i64, 1>>().unwrap();
+    let key = value.to_memcomparable_normalized();
+    assert_eq!(
+        "-1.500"
+            .parse::<Decimal<i128, 3>>()
+            .unwrap()
+            .to_memcomparable_normalized(),
+        key
+    );
+    assert_eq!(
+        Decimal::<i64, 0>::from_memcomparable_normalized(&key),
+        Err(DecimalError::TooManyFractionalDigits {
+            provided: 1,
+            allowed: 0
+        })
+    );
+
+    let key = Decimal::<i128, 0>::MAX.to_memcomparable_normalized();
+    assert_eq!(
+        Decimal::<i64, 0>::from_memcomparable_normalized(&key),
+        Err(DecimalError::Overflow)
+    );
+    assert_eq!(
+        Decimal::<i128, 1>::from_memcomparable_normalized(&key),
+        Err(DecimalError::Overflow)
+    );
+}
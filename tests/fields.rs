@@ -0,0 +1,184 @@
+use decimal::fields::{self, DigitLimits};
+use decimal::money::{Currency, Money};
+use decimal::{Decimal, DecimalError};
+
+fn d2(input: &str) -> Decimal<i64, 2> {
+    input.parse().unwrap()
+}
+
+fn d6(input: &str) -> Decimal<i64, 6> {
+    input.parse().unwrap()
+}
+
+fn usd(input: &str) -> Money<i64, 6> {
+    Money::new(d6(input), Currency::from_code("USD").unwrap())
+}
+
+#[test]
+fn digit_limits() {
+    let limits = DigitLimits::new(5, 2).unwrap();
+    assert_eq!((limits.total(), limits.fractional()), (5, 2));
+    assert_eq!(DigitLimits::new(0, 0), Err(DecimalError::Invalid));
+    assert_eq!(DigitLimits::new(2, 3), Err(DecimalError::Invalid));
+
+    // Leading zeros and trailing fractional zeros are not significant.
+    assert_eq!(limits.check(d6("123.450000")), Ok(()));
+    assert_eq!(limits.check(d6("0.05")), Ok(()));
+    assert_eq!(limits.check(d6("0")), Ok(()));
+    assert_eq!(limits.check(d6("1234.5")), Ok(()));
+    assert_eq!(
+        limits.check(d6("12345.6")),
+        Err(DecimalError::TooManyDigits {
+            provided: 6,
+            allowed: 5
+        })
+    );
+    assert_eq!(
+        limits.check(d6("-1.234")),
+        Err(DecimalError::TooManyFractionalDigits {
+            provided: 3,
+            allowed: 2
+        })
+    );
+}
+
+#[test]
+fn fix_fields() {
+    let limits = DigitLimits::FIX_FLOAT;
+    let parse = |text: &str| fields::parse_fix::<i64, 6>(text, limits);
+
+    assert_eq!(parse("123.45"), Ok(d6("123.45")));
+    assert_eq!(parse("-0.5"), Ok(d6("-0.5")));
+    assert_eq!(parse("00023.2300"), Ok(d6("23.23")));
+    assert_eq!(parse("42"), Ok(d6("42")));
+    assert_eq!(parse(".5"), Ok(d6("0.5")));
+    assert_eq!(parse("5."), Ok(d6("5")));
+
+    assert_eq!(parse(""), Err(DecimalError::Empty));
+    for text in ["+1", "1e3", "1,5", ".", "-", "--1", "1.2.3", " 1"] {
+        assert_eq!(parse(text), Err(DecimalError::Invalid), "{text}");
+    }
+    assert_eq!(
+        parse("1234567890.123456"),
+        Err(DecimalError::TooManyDigits {
+            provided: 16,
+            allowed: 15
+        })
+    );
+    // Within the FIX limits but finer than the type.
+    assert_eq!(
+        parse("0.1234567"),
+        Err(DecimalError::TooManyFractionalDigits {
+            provided: 7,
+            allowed: 6
+        })
+    );
+
+    assert_eq!(
+        fields::format_fix(d6("123.450000"), limits).unwrap(),
+        "123.45"
+    );
+    assert_eq!(fields::format_fix(d6("-7"), limits).unwrap(), "-7");
+    assert_eq!(fields::format_fix(d2("0"), limits).unwrap(), "0");
+    assert_eq!(fields::format_fix(d2("-0.05"), limits).unwrap(), "-0.05");
+    assert_eq!(
+        fields::format_fix(d2("123.45"), DigitLimits::new(4, 2).unwrap()),
+        Err(DecimalError::TooManyDigits {
+            provided: 5,
+            allowed: 4
+        })
+    );
+}
+
+#[test]
+fn iso20022_amounts() {
+    let parse = fields::parse_iso20022_amount::<i64, 6>;
+
+    assert_eq!(parse("1234.5", "USD"), Ok(usd("1234.5")));
+    assert_eq!(parse("+0.10", "USD"), Ok(usd("0.1")));
+    assert_eq!(parse("-0", "USD"), Ok(usd("0")));
+    assert_eq!(parse("-1.00", "USD"), Err(DecimalError::Invalid));
+    assert_eq!(parse("1e2", "USD"), Err(DecimalError::Invalid));
+    assert_eq!(parse("", "USD"), Err(DecimalError::Empty));
+    assert_eq!(parse("1", "XYZ"), Err(DecimalError::Invalid));
+    assert_eq!(parse("1", ""), Err(DecimalError::Empty));
+
+    // totalDigits 18 and fractionDigits 5.
+    assert_eq!(
+        parse("1.123456", "BHD"),
+        Err(DecimalError::TooManyFractionalDigits {
+            provided: 6,
+            allowed: 5
+        })
+    );
+    assert_eq!(
+        fields::parse_iso20022_amount::<i128, 2>("1234567890123456789", "USD"),
+        Err(DecimalError::TooManyDigits {
+            provided: 19,
+            allowed: 18
+        })
+    );
+    assert!(fields::parse_iso20022_amount::<i128, 2>("1234567890123456.78", "USD").is_ok());
+
+    // Decimals beyond the currency's minor unit.
+    assert_eq!(
+        parse("1.005", "USD"),
+        Err(DecimalError::TooManyFractionalDigits {
+            provided: 3,
+            allowed: 2
+        })
+    );
+    assert_eq!(
+        parse("1500.5", "JPY"),
+        Err(DecimalError::TooManyFractionalDigits {
+            provided: 1,
+            allowed: 0
+        })
+    );
+    assert!(parse("1.005", "BHD").is_ok());
+}
+
+#[test]
+fn iso20022_formatting() {
+    assert_eq!(
+        fields::format_iso20022_amount(usd("1234.5")).unwrap(),
+        "1234.50"
+    );
+    assert_eq!(fields::format_iso20022_amount(usd("0")).unwrap(), "0.00");
+
+    let jpy = Currency::from_code("JPY").unwrap();
+    let yen = Money::new(d2("1500"), jpy);
+    assert_eq!(fields::format_iso20022_amount(yen).unwrap(), "1500");
+    assert_eq!(
+        fields::format_iso20022_amount(Money::new(d2("1500.5"), jpy)),
+        Err(DecimalError::TooManyFractionalDigits {
+            provided: 1,
+            allowed: 0
+        })
+    );
+
+    let dinar = Money::new(d2("1.5"), Currency::from_code("BHD").unwrap());
+    assert_eq!(fields::format_iso20022_amount(dinar).unwrap(), "1.500");
+
+    assert_eq!(
+        fields::format_iso20022_amount(usd("-1")),
+        Err(DecimalError::Invalid)
+    );
+    assert_eq!(
+        fields::format_iso20022_amount(usd("0.001")),
+        Err(DecimalError::TooManyFractionalDigits {
+            provided: 3,
+            allowed: 2
+        })
+    );
+
+    // Round trip through the formatter.
+    for text in ["0.01", "99.99", "1000000"] {
+        let money = usd(text);
+        let amount = fields::format_iso20022_amount(money).unwrap();
+        assert_eq!(
+            fields::parse_iso20022_amount::<i64, 6>(&amount, "USD"),
+            Ok(money)
+        );
+    }
+}
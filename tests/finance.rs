@@ -0,0 +1,304 @@
+use decimal::finance::{self, PaymentTiming};
+use decimal::{Decimal, DecimalError, RoundingMode};
+
+fn d2(input: &str) -> Decimal<i64, 2> {
+    input.parse().unwrap()
+}
+
+fn r4(input: &str) -> Decimal<i64, 4> {
+    input.parse().unwrap()
+}
+
+#[test]
+fn compound_interest() {
+    // 1000 * 1.05^10 = 1628.894626777...
+    let value = finance::compound(d2("1000.00"), r4("0.05"), 10, RoundingMode::HalfUp).unwrap();
+    assert_eq!(value.to_string(), "1628.89");
+}
+
+#[test]
+fn long_horizons() {
+    assert_eq!(
+        finance::compound(d2("100.00"), r4("0.05"), 1_000_000, RoundingMode::HalfUp),
+        Err(DecimalError::Overflow)
+    );
+    assert_eq!(
+        finance::compound(d2("0.00"), r4("0.05"), 1_000_000, RoundingMode::HalfUp),
+        Ok(d2("0.00"))
+    );
+    // 100 * (1 + 10^-8)^1000000 = 101.00501670...
+    let rate: Decimal<i64, 8> = "0.00000001".parse().unwrap();
+    let value = finance::compound(d2("100.00"), rate, 1_000_000, RoundingMode::HalfUp).unwrap();
+    assert_eq!(value.to_string(), "101.01");
+    assert_eq!(
+        finance::compound(d2("100.00"), r4("-0.05"), 1_000_000, RoundingMode::Ceiling),
+        Ok(d2("0.01"))
+    );
+
+    // A perpetuity of 100 at 5% is worth just under 2000.
+    let pv = |mode| {
+        finance::pv(
+            r4("0.05"),
+            1_000_000,
+            d2("-100.00"),
+            d2("0"),
+            PaymentTiming::End,
+            mode,
+        )
+    };
+    assert_eq!(pv(RoundingMode::HalfUp).unwrap().to_string(), "2000.00");
+    assert_eq!(pv(RoundingMode::Truncate).unwrap().to_string(), "1999.99");
+    let payment = finance::pmt(
+        r4("0.05"),
+        1_000_000,
+        d2("1000.00"),
+        d2("0"),
+        PaymentTiming::End,
+        RoundingMode::HalfUp,
+    );
+    assert_eq!(payment.unwrap().to_string(), "-50.00");
+    assert_eq!(
+        finance::fv(
+            r4("0.05"),
+            1_000_000,
+            d2("-100.00"),
+            d2("0"),
+            PaymentTiming::End,
+            RoundingMode::HalfUp
+        ),
+        Err(DecimalError::Overflow)
+    );
+    let rate = finance::rate::<i64, 2, 4>(
+        1_000_000,
+        d2("-50.00"),
+        d2("1000.00"),
+        d2("0"),
+        PaymentTiming::End,
+        RoundingMode::HalfUp,
+    );
+    assert_eq!(rate.unwrap().to_string(), "0.0500");
+}
+
+#[test]
+fn mortgage_payment() {
+    // 6% a year, monthly, 30 years: -1199.1010...
+    let rate: Decimal<i64, 3> = "0.005".parse().unwrap();
+    let payment = finance::pmt(
+        rate,
+        360,
+        d2("200000.00"),
+        d2("0"),
+        PaymentTiming::End,
+        RoundingMode::HalfUp,
+    )
+    .unwrap();
+    assert_eq!(payment.to_string(), "-1199.10");
+
+    // Annuity due: -87.969...
+    let payment = finance::pmt(
+        r4("0.01"),
+        12,
+        d2("1000.00"),
+        d2("0"),
+        PaymentTiming::Beginning,
+        RoundingMode::HalfUp,
+    )
+    .unwrap();
+    assert_eq!(payment.to_string(), "-87.97");
+}
+
+#[test]
+fn future_and_present_value() {
+    let rate: Decimal<i64, 3> = "0.005".parse().unwrap();
+    let end = finance::fv(
+        rate,
+        120,
+        d2("-100"),
+        d2("-100"),
+        PaymentTiming::End,
+        RoundingMode::HalfUp,
+    )
+    .unwrap();
+    assert_eq!(end.to_string(), "16569.87");
+
+    let beginning = finance::fv(
+        rate,
+        120,
+        d2("-100"),
+        d2("-100"),
+        PaymentTiming::Beginning,
+        RoundingMode::HalfUp,
+    )
+    .unwrap();
+    assert_eq!(beginning.to_string(), "16651.81");
+
+    // -4909.0737...
+    let present = finance::pv(
+        r4("0.08"),
+        20,
+        d2("500"),
+        d2("0"),
+        PaymentTiming::End,
+        RoundingMode::HalfEven,
+    )
+    .unwrap();
+    assert_eq!(present.to_string(), "-4909.07");
+}
+
+#[test]
+fn zero_rate() {
+    let zero = r4("0");
+    let value = finance::fv(
+        zero,
+        12,
+        d2("-10"),
+        d2("-100"),
+        PaymentTiming::End,
+        RoundingMode::HalfUp,
+    );
+    assert_eq!(value.unwrap().to_string(), "220.00");
+
+    let payment = finance::pmt(
+        zero,
+        3,
+        d2("100"),
+        d2("0"),
+        PaymentTiming::End,
+        RoundingMode::HalfUp,
+    );
+    assert_eq!(payment.unwrap().to_string(), "-33.33");
+
+    let payment = finance::pmt(
+        zero,
+        0,
+        d2("100"),
+        d2("0"),
+        PaymentTiming::End,
+        RoundingMode::HalfUp,
+    );
+    assert_eq!(payment, Err(DecimalError::DivisionByZero));
+}
+
+#[test]
+fn number_of_periods() {
+    // 10.58864445942...
+    let periods: Decimal<i64, 6> = finance::nper(
+        r4("0.01"),
+        d2("-100"),
+        d2("1000"),
+        d2("0"),
+        PaymentTiming::End,
+        RoundingMode::HalfUp,
+    )
+    .unwrap();
+    assert_eq!(periods.to_string(), "10.588644");
+
+    // Payments too small to cover the interest never repay the loan.
+    let never = finance::nper::<i64, 2, 4, 2>(
+        r4("0.01"),
+        d2("-5"),
+        d2("1000"),
+        d2("0"),
+        PaymentTiming::End,
+        RoundingMode::HalfUp,
+    );
+    assert_eq!(never, Err(DecimalError::NoSolution));
+}
+
+#[test]
+fn rate_solves_for_growth() {
+    // 100 doubling in 10 periods: 2^(1/10) - 1 = 0.0717734625...
+    let rate: Decimal<i64, 8> = finance::rate(
+        10,
+        d2("0"),
+        d2("-100"),
+        d2("200"),
+        PaymentTiming::End,
+        RoundingMode::HalfUp,
+    )
+    .unwrap();
+    assert_eq!(rate.to_string(), "0.07177346");
+
+    let truncated: Decimal<i64, 3> = finance::rate(
+        10,
+        d2("0"),
+        d2("-100"),
+        d2("200"),
+        PaymentTiming::End,
+        RoundingMode::Truncate,
+    )
+    .unwrap();
+    assert_eq!(truncated.to_string(), "0.071");
+
+    // The rate the mortgage payment was computed from comes back out.
+    let monthly: Decimal<i64, 4> = finance::rate(
+        360,
+        d2("-1199.10"),
+        d2("200000"),
+        d2("0"),
+        PaymentTiming::End,
+        RoundingMode::HalfUp,
+    )
+    .unwrap();
+    assert_eq!(monthly.to_string(), "0.0050");
+}
+
+#[test]
+fn net_present_value_and_irr() {
+    let flows = ["-100", "60", "60"].map(d2);
+    // -100 + 60/1.1 + 60/1.21 = 4.1322...
+    let npv = finance::npv(r4("0.1"), &flows, RoundingMode::HalfUp).unwrap();
+    assert_eq!(npv.to_string(), "4.13");
+
+    let flows = ["-100", "39", "59", "55", "20"].map(d2);
+    // 0.28094842115996...
+    let irr: Decimal<i64, 8> = finance::irr(&flows, RoundingMode::HalfUp).unwrap();
+    assert_eq!(irr.to_string(), "0.28094842");
+    let at_irr = finance::npv(irr, &flows, RoundingMode::HalfUp).unwrap();
+    assert_eq!(at_irr.to_string(), "0.00");
+
+    let exact: Decimal<i64, 2> =
+        finance::irr(&["-100", "110"].map(d2), RoundingMode::Truncate).unwrap();
+    assert_eq!(exact.to_string(), "0.10");
+
+    let all_positive = finance::irr::<i64, 2, 4>(&["100", "10"].map(d2), RoundingMode::HalfUp);
+    assert_eq!(all_positive, Err(DecimalError::NoSolution));
+    let empty = finance::irr::<i64, 2, 4>(&[], RoundingMode::HalfUp);
+    assert_eq!(empty, Err(DecimalError::Empty));
+}
+
+#[test]
+fn amortization_reconciles() {
+    let loan = d2("10000.00");
+    let rate: Decimal<i64, 6> = "0.008333".parse().unwrap();
+    let rows = finance::amortization_schedule(loan, rate, 36, RoundingMode::HalfEven).unwrap();
+    assert_eq!(rows.len(), 36);
+
+    let principal: Decimal<i64, 2> = rows.iter().map(|row| row.principal).sum();
+    assert_eq!(principal, loan);
+    assert!(rows.last().unwrap().balance.is_zero());
+
+    for row in &rows {
+        assert_eq!(row.principal.checked_add(row.interest), Some(row.payment));
+    }
+    assert_eq!(rows[0].period, 1);
+    assert_eq!(rows[0].interest.to_string(), "83.33");
+    assert_eq!(rows[0].payment, rows[1].payment);
+}
+
+#[test]
+fn amortization_zero_rate() {
+    let rows =
+        finance::amortization_schedule(d2("100.00"), r4("0"), 3, RoundingMode::HalfUp).unwrap();
+    let payments: Vec<String> = rows.iter().map(|row| row.payment.to_string()).collect();
+    assert_eq!(payments, ["33.33", "33.33", "33.34"]);
+}
+
+#[test]
+fn amortization_period_bound() {
+    assert_eq!(
+        finance::amortization_schedule(d2("100.00"), r4("0"), u32::MAX, RoundingMode::HalfUp)
+            .unwrap_err(),
+        DecimalError::Invalid
+    );
+}
@@ -0,0 +1,87 @@
+use decimal::{Decimal, DecimalError, RoundingMode};
+
+fn d2(input: &str) -> Decimal<i64, 2> {
+    input.parse().unwrap()
+}
+
+#[test]
+fn sum_owned_and_borrowed() {
+    let entries = [d2("1.25"), d2("-0.50"), d2("10.00")];
+    let owned: Decimal<i64, 2> = entries.iter().copied().sum();
+    assert_eq!(owned.to_string(), "10.75");
+
+    let borrowed: Decimal<i64, 2> = entries.iter().sum();
+    assert_eq!(borrowed, owned);
+
+    let empty: Decimal<i64, 2> = Vec::<Decimal<i64, 2>>::new().into_iter().sum();
+    assert!(empty.is_zero());
+}
+
+#[test]
+fn checked_sum_tolerates_intermediate_overflow() {
+    let entries = [Decimal::<i64, 2>::MAX, d2("1.00"), d2("-1.00")];
+    let total = Decimal::checked_sum(entries).unwrap();
+    assert_eq!(total, Decimal::<i64, 2>::MAX);
+}
+
+#[test]
+fn checked_sum_overflow() {
+    let entries = [Decimal::<i64, 2>::MAX, d2("0.01")];
+    assert_eq!(
+        Decimal::checked_sum(entries).unwrap_err(),
+        DecimalError::Overflow
+    );
+
+    let entries = [Decimal::<i128, 2>::MAX, Decimal::EPSILON];
+    assert_eq!(
+        Decimal::checked_sum(entries).unwrap_err(),
+        DecimalError::Overflow
+    );
+}
+
+#[test]
+#[should_panic]
+fn sum_overflow_panics() {
+    let _: Decimal<i64, 2> = [Decimal::<i64, 2>::MAX, d2("0.01")].into_iter().sum();
+}
+
+#[test]
+fn product_rounding() {
+    let factors = [d2("1.05"), d2("1.05"), d2("1.05")];
+    let truncate = Decimal::product(factors, RoundingMode::Truncate).unwrap();
+    assert_eq!(truncate.to_string(), "1.15");
+
+    let half_up = Decimal::product(factors, RoundingMode::HalfUp).unwrap();
+    assert_eq!(half_up.to_string(), "1.16");
+
+    let negative = Decimal::product([d2("-2.50"), d2("0.50")], RoundingMode::HalfEven).unwrap();
+    assert_eq!(negative.to_string(), "-1.25");
+
+    let empty = Decimal::<i64, 2>::product([], RoundingMode::HalfUp).unwrap();
+    assert_eq!(empty, Decimal::one());
+}
+
+#[test]
+fn product_rounds_once() {
+    // 0.15 * 0.15 = 0.0225 would round to 0.02 before the last factor.
+    let factors = [d2("0.15"), d2("0.15"), d2("100.00")];
+    let value = Decimal::product(factors, RoundingMode::Truncate).unwrap();
+    assert_eq!(value.to_string(), "2.25");
+
+    let factors = [d2("-0.15"), d2("0.15"), d2("0.15")];
+    let floor = Decimal::product(factors, RoundingMode::Floor).unwrap();
+    assert_eq!(floor.to_string(), "-0.01");
+    let ceiling = Decimal::product(factors, RoundingMode::Ceiling).unwrap();
+    assert_eq!(ceiling.to_string(), "0.00");
+}
+
+#[test]
+fn product_wide_intermediate() {
+    let big = "90000000000.0000".parse::<Decimal<i64, 4>>().unwrap();
+    let small = "0.0001".parse::<Decimal<i64, 4>>().unwrap();
+    let value = Decimal::product([big, small], RoundingMode::HalfUp).unwrap();
+    assert_eq!(value.to_string(), "9000000.0000");
+
+    let err = Decimal::product([big, big], RoundingMode::HalfUp).unwrap_err();
+    assert_eq!(err, DecimalError::Overflow);
+}
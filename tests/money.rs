@@ -0,0 +1,165 @@
+use decimal::money::{Currency, ExchangeRate, Money};
+use decimal::{Decimal, DecimalError, RoundingMode};
+
+fn usd(input: &str) -> Money<i64, 4> {
+    Money::new(input.parse().unwrap(), Currency::USD)
+}
+
+#[test]
+fn currency_table() {
+    assert_eq!(Currency::from_code("JPY").unwrap().minor_units(), 0);
+    assert_eq!(Currency::from_code("KWD").unwrap().minor_units(), 3);
+    assert_eq!(Currency::from_code("CLF").unwrap().minor_units(), 4);
+    assert_eq!(Currency::from_numeric(978), Some(Currency::EUR));
+    assert_eq!("GBP".parse::<Currency>(), Ok(Currency::GBP));
+    assert_eq!("XYZ".parse::<Currency>(), Err(DecimalError::Invalid));
+    assert_eq!("".parse::<Currency>(), Err(DecimalError::Empty));
+
+    let codes: Vec<&str> = Currency::all()
+        .iter()
+        .map(|currency| currency.code())
+        .collect();
+    assert!(codes.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[test]
+fn same_currency_arithmetic() {
+    let total = usd("10.25").checked_add(usd("0.75")).unwrap();
+    assert_eq!(total.amount().to_string(), "11.0000");
+    assert_eq!(total.currency(), Currency::USD);
+
+    let change = usd("5").checked_sub(usd("7.5")).unwrap();
+    assert_eq!(change.to_string(), "USD -2.50");
+}
+
+#[test]
+fn cross_currency_is_refused() {
+    let euros = Money::new("1.00".parse().unwrap(), Currency::EUR);
+    assert_eq!(
+        usd("1").checked_add(euros),
+        Err(DecimalError::CurrencyMismatch)
+    );
+    assert_eq!(
+        usd("1").checked_sub(euros),
+        Err(DecimalError::CurrencyMismatch)
+    );
+}
+
+#[test]
+fn rounds_to_minor_unit() {
+    let price: Decimal<i64, 4> = "1234.5678".parse().unwrap();
+
+    let dollars = Money::new(price, Currency::USD)
+        .round_to_minor_unit(RoundingMode::HalfUp)
+        .unwrap();
+    assert_eq!(dollars.amount().to_string(), "1234.5700");
+
+    let yen = Money::new(price, Currency::JPY)
+        .round_to_minor_unit(RoundingMode::HalfEven)
+        .unwrap();
+    assert_eq!(yen.to_string(), "JPY 1235");
+
+    let dinars = Money::new(price, Currency::KWD)
+        .round_to_minor_unit(RoundingMode::Truncate)
+        .unwrap();
+    assert_eq!(dinars.to_string(), "KWD 1234.567");
+}
+
+#[test]
+fn formatting() {
+    assert_eq!(usd("1234.5").to_string(), "USD 1234.50");
+    assert_eq!(usd("1234.5").format_with_symbol(), "$1234.50");
+    assert_eq!(usd("-0.5").format_with_symbol(), "-$0.50");
+    // Digits below the minor unit are shown rather than hidden.
+    assert_eq!(usd("0.125").to_string(), "USD 0.1250");
+
+    let whole: Money<i64, 0> = Money::new("42".parse().unwrap(), Currency::KWD);
+    assert_eq!(whole.to_string(), "KWD 42.000");
+    let yen: Money<i64, 0> = Money::new("1500".parse().unwrap(), Currency::JPY);
+    assert_eq!(yen.format_with_symbol(), "¥1500");
+    assert_eq!(Money::<i64, 2>::zero(Currency::CHF).to_string(), "CHF 0.00");
+}
+
+fn rate<const SCALE: u32>(base: Currency, quote: Currency, rate: &str) -> ExchangeRate<i64, SCALE> {
+    ExchangeRate::new(base, quote, rate.parse().unwrap()).unwrap()
+}
+
+#[test]
+fn exchange_rate_validation() {
+    let zero = ExchangeRate::new(Currency::EUR, Currency::USD, Decimal::<i64, 4>::zero());
+    assert_eq!(zero, Err(DecimalError::Invalid));
+    let same = ExchangeRate::new(Currency::EUR, Currency::EUR, Decimal::<i64, 4>::one());
+    assert_eq!(same, Err(DecimalError::Invalid));
+
+    let eur_usd = rate::<4>(Currency::EUR, Currency::USD, "1.085");
+    assert_eq!(eur_usd.to_string(), "EUR/USD 1.0850");
+}
+
+#[test]
+fn exchange_rate_convert() {
+    let eur_usd = rate::<4>(Currency::EUR, Currency::USD, "1.0853");
+    let euros: Money<i64, 2> = Money::new("100.00".parse().unwrap(), Currency::EUR);
+
+    // 100 * 1.0853 = 108.53
+    let dollars = eur_usd.convert(euros, RoundingMode::HalfUp).unwrap();
+    assert_eq!(dollars.to_string(), "USD 108.53");
+
+    // 50 / 1.0853 = 46.0702...
+    let dollars: Money<i64, 2> = Money::new("50.00".parse().unwrap(), Currency::USD);
+    let euros = eur_usd.convert(dollars, RoundingMode::HalfUp).unwrap();
+    assert_eq!(euros.to_string(), "EUR 46.07");
+
+    let yen: Money<i64, 2> = Money::new("1".parse().unwrap(), Currency::JPY);
+    assert_eq!(
+        eur_usd.convert(yen, RoundingMode::HalfUp),
+        Err(DecimalError::CurrencyMismatch)
+    );
+}
+
+#[test]
+fn exchange_rate_invert() {
+    let eur_usd = rate::<4>(Currency::EUR, Currency::USD, "1.0853");
+    // 1 / 1.0853 = 0.921404220...
+    let usd_eur: ExchangeRate<i64, 6> = eur_usd.invert(RoundingMode::HalfUp).unwrap();
+    assert_eq!(usd_eur.to_string(), "USD/EUR 0.921404");
+    let truncated: ExchangeRate<i64, 2> = eur_usd.invert(RoundingMode::Truncate).unwrap();
+    assert_eq!(truncated.rate().to_string(), "0.92");
+}
+
+#[test]
+fn exchange_rate_cross() {
+    let eur_usd = rate::<4>(Currency::EUR, Currency::USD, "1.0853");
+    let usd_jpy = rate::<2>(Currency::USD, Currency::JPY, "151.37");
+    let gbp_usd = rate::<4>(Currency::GBP, Currency::USD, "1.2650");
+
+    // 1.0853 * 151.37 = 164.281861
+    let eur_jpy: ExchangeRate<i64, 3> = eur_usd.cross(usd_jpy, RoundingMode::HalfUp).unwrap();
+    assert_eq!(eur_jpy.to_string(), "EUR/JPY 164.282");
+
+    // 1.0853 / 1.2650 = 0.857944664...
+    let eur_gbp: ExchangeRate<i64, 6> = eur_usd.cross(gbp_usd, RoundingMode::HalfUp).unwrap();
+    assert_eq!(eur_gbp.to_string(), "EUR/GBP 0.857945");
+
+    // Through a shared base: (1 / 1.0853) * 151.37 via USD/EUR order.
+    let usd_eur = rate::<6>(Currency::USD, Currency::EUR, "0.921404");
+    let eur_jpy: ExchangeRate<i64, 2> = usd_eur.cross(usd_jpy, RoundingMode::HalfUp).unwrap();
+    // 151.37 / 0.921404 = 164.2818...
+    assert_eq!(eur_jpy.to_string(), "EUR/JPY 164.28");
+
+    // Both legs inverted: 1 / (0.921404 * 0.006606) = 164.2900...
+    let jpy_usd = rate::<6>(Currency::JPY, Currency::USD, "0.006606");
+    let eur_jpy: ExchangeRate<i64, 2> = usd_eur.cross(jpy_usd, RoundingMode::HalfUp).unwrap();
+    assert_eq!(eur_jpy.to_string(), "EUR/JPY 164.29");
+
+    // No currency in common.
+    let chf_jpy = rate::<2>(Currency::CHF, Currency::JPY, "170.10");
+    assert_eq!(
+        eur_usd.cross::<2, 2>(chf_jpy, RoundingMode::HalfUp),
+        Err(DecimalError::CurrencyMismatch)
+    );
+    let usd_eur: ExchangeRate<i64, 4> = eur_usd.invert(RoundingMode::HalfUp).unwrap();
+    assert_eq!(
+        eur_usd.cross::<4, 4>(usd_eur, RoundingMode::HalfUp),
+        Err(DecimalError::CurrencyMismatch)
+    );
+}
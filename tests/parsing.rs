@@ -55,3 +55,98 @@ fn parse_i128_backing() {
     let value = "92233720368547758.08".parse::<Decimal<i128, 2>>().unwrap();
     assert_eq!(value.to_string(), "92233720368547758.08");
 }
+
+#[test]
+fn parse_with_default_options_matches_from_str() {
+    use decimal::ParseOptions;
+
+    for input in ["0", "-0", "1.23", "1234.", ".5", "-.25", "+7.1"] {
+        assert_eq!(
+            Decimal::<i64, 2>::parse_with(input.as_bytes(), ParseOptions::default()),
+            input.parse::<Decimal<i64, 2>>(),
+            "{input}"
+        );
+    }
+    for input in ["", "-", ".", "1,000", " 1", "(1)", "1.234", "1e3"] {
+        assert!(
+            Decimal::<i64, 2>::parse_with(input.as_bytes(), ParseOptions::default()).is_err(),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn parse_edge_cases_match_parse_with() {
+    use decimal::{DecimalError, ParseOptions};
+
+    let min = i128::MIN.to_string();
+    assert_eq!(
+        min.parse::<Decimal<i128, 0>>().unwrap().minor_units(),
+        i128::MIN
+    );
+    for input in ["1.234x", "1.2.3", "-1.2345"] {
+        assert_eq!(
+            input.parse::<Decimal<i64, 2>>(),
+            Decimal::<i64, 2>::parse_with(input.as_bytes(), ParseOptions::default()),
+            "{input}"
+        );
+    }
+    assert_eq!(
+        "1.234x".parse::<Decimal<i64, 2>>(),
+        Err(DecimalError::Invalid)
+    );
+}
+
+#[test]
+fn parse_with_lenient_options() {
+    use decimal::{DecimalError, ParseOptions, RoundingMode};
+
+    let options = ParseOptions {
+        decimal_separator: b',',
+        group_separator: Some(b'.'),
+        trim_whitespace: true,
+        parentheses_negative: true,
+        rounding: None,
+    };
+    let parse = |input: &str| Decimal::<i64, 2>::parse_with(input.as_bytes(), options);
+    assert_eq!(parse("  1.234.567,8 ").unwrap().to_string(), "1234567.80");
+    assert_eq!(parse("(1.234,50)").unwrap().to_string(), "-1234.50");
+    assert_eq!(parse("( 12,5 )").unwrap().to_string(), "-12.50");
+    assert_eq!(parse("-0,01").unwrap().to_string(), "-0.01");
+    assert_eq!(parse("   "), Err(DecimalError::Empty));
+    for input in ["1..000", ".1", "1.", "(-1)", "(1", "1,2,3", "1.000,5."] {
+        assert_eq!(parse(input), Err(DecimalError::Invalid), "{input}");
+    }
+    assert_eq!(
+        parse("1,234"),
+        Err(DecimalError::TooManyFractionalDigits {
+            provided: 3,
+            allowed: 2
+        })
+    );
+    assert_eq!(
+        Decimal::<i64, 2>::parse_with(b"92233720368547758.08", ParseOptions::default()),
+        Err(DecimalError::Overflow)
+    );
+
+    let cases = [
+        ("1.005", RoundingMode::HalfUp, "1.01"),
+        ("1.005", RoundingMode::HalfEven, "1.00"),
+        ("1.015", RoundingMode::HalfEven, "1.02"),
+        ("1.0050001", RoundingMode::HalfEven, "1.01"),
+        ("-1.005", RoundingMode::HalfUp, "-1.01"),
+        ("1.001", RoundingMode::Truncate, "1.00"),
+        ("1.0001", RoundingMode::Ceiling, "1.01"),
+        ("-1.0001", RoundingMode::Ceiling, "-1.00"),
+        ("-1.0001", RoundingMode::Floor, "-1.01"),
+        ("1.0099", RoundingMode::Floor, "1.00"),
+    ];
+    for (input, mode, expected) in cases {
+        let options = ParseOptions {
+            rounding: Some(mode),
+            ..ParseOptions::default()
+        };
+        let value = Decimal::<i64, 2>::parse_with(input.as_bytes(), options).unwrap();
+        assert_eq!(value.to_string(), expected, "{input} {mode:?}");
+    }
+}
@@ -0,0 +1,101 @@
+use decimal::{BasisPoints, Decimal, DecimalError, Percent, RoundingMode};
+
+fn d2(input: &str) -> Decimal<i64, 2> {
+    input.parse().unwrap()
+}
+
+fn pct(input: &str) -> Percent<i64, 2> {
+    Percent::new(input.parse().unwrap())
+}
+
+#[test]
+fn percent_conversions() {
+    let rate = pct("5.25");
+    assert_eq!(rate.to_string(), "5.25%");
+
+    let fraction: Decimal<i64, 4> = rate.to_fraction(RoundingMode::HalfUp).unwrap();
+    assert_eq!(fraction.to_string(), "0.0525");
+    let rounded: Decimal<i64, 3> = rate.to_fraction(RoundingMode::HalfEven).unwrap();
+    assert_eq!(rounded.to_string(), "0.052");
+
+    let back: Percent<i64, 2> = Percent::from_fraction(fraction, RoundingMode::HalfUp).unwrap();
+    assert_eq!(back, rate);
+
+    let bps = rate.to_basis_points(RoundingMode::HalfUp).unwrap();
+    assert_eq!(bps.value(), 525);
+}
+
+#[test]
+fn basis_point_conversions() {
+    let spread = BasisPoints::new(25_i64);
+    assert_eq!(spread.to_string(), "25bp");
+
+    let fraction: Decimal<i64, 4> = spread.to_fraction(RoundingMode::HalfUp).unwrap();
+    assert_eq!(fraction.to_string(), "0.0025");
+    let percent: Percent<i64, 2> = spread.to_percent(RoundingMode::HalfUp).unwrap();
+    assert_eq!(percent.to_string(), "0.25%");
+
+    // 0.0025 cannot be held at two decimals without rounding.
+    let coarse: Decimal<i64, 2> = spread.to_fraction(RoundingMode::Truncate).unwrap();
+    assert!(coarse.is_zero());
+}
+
+#[test]
+fn apply_to_amount() {
+    let amount = d2("1234.56");
+    // 5% of 1234.56 = 61.728
+    assert_eq!(
+        pct("5")
+            .apply_to(amount, RoundingMode::HalfUp)
+            .unwrap()
+            .to_string(),
+        "61.73"
+    );
+    assert_eq!(
+        pct("5")
+            .apply_to(amount, RoundingMode::Truncate)
+            .unwrap()
+            .to_string(),
+        "61.72"
+    );
+    // 25bp of 1234.56 = 3.0864
+    assert_eq!(
+        BasisPoints::new(25_i64)
+            .apply_to(amount, RoundingMode::HalfUp)
+            .unwrap()
+            .to_string(),
+        "3.09"
+    );
+    assert_eq!(
+        pct("-10")
+            .apply_to(d2("-50.00"), RoundingMode::HalfUp)
+            .unwrap()
+            .to_string(),
+        "5.00"
+    );
+}
+
+#[test]
+fn change_and_difference() {
+    let change: Percent<i64, 2> =
+        Percent::change(d2("80.00"), d2("100.00"), RoundingMode::HalfUp).unwrap();
+    assert_eq!(change.to_string(), "25.00%");
+
+    let change: Percent<i64, 2> =
+        Percent::change(d2("-80.00"), d2("-60.00"), RoundingMode::HalfUp).unwrap();
+    assert_eq!(change.to_string(), "25.00%");
+
+    let change: Percent<i64, 2> =
+        Percent::change(d2("3.00"), d2("2.00"), RoundingMode::HalfUp).unwrap();
+    assert_eq!(change.to_string(), "-33.33%");
+
+    // |80 - 100| / 90 = 22.22...%
+    let difference: Percent<i64, 2> =
+        Percent::difference(d2("80.00"), d2("100.00"), RoundingMode::HalfUp).unwrap();
+    assert_eq!(difference.to_string(), "22.22%");
+
+    assert_eq!(
+        Percent::<i64, 2>::change(d2("0"), d2("1.00"), RoundingMode::HalfUp),
+        Err(DecimalError::DivisionByZero)
+    );
+}
@@ -0,0 +1,174 @@
+use decimal::{Decimal, DecimalError, RoundingMode};
+
+/// `numeric_send` output for each value, as PostgreSQL lays it out.
+const FIXTURES: &[(&str, &[u8])] = &[
+    // SELECT numeric_send(1234.5678)
+    (
+        "1234.5678",
+        &[
+            0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x04, 0xd2, 0x16, 0x2e,
+        ],
+    ),
+    // SELECT numeric_send(-0.001)
+    (
+        "-0.001",
+        &[0x00, 0x01, 0xff, 0xff, 0x40, 0x00, 0x00, 0x03, 0x00, 0x0a],
+    ),
+    // SELECT numeric_send(12345678.9)
+    (
+        "12345678.9",
+        &[
+            0x00, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x04, 0xd2, 0x16, 0x2e, 0x23, 0x28,
+        ],
+    ),
+    // SELECT numeric_send(10000)
+    (
+        "10000",
+        &[0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01],
+    ),
+    // SELECT numeric_send(0.00)
+    ("0.00", &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02]),
+];
+
+const NAN: &[u8] = &[0x00, 0x00, 0x00, 0x00, 0xc0, 0x00, 0x00, 0x00];
+const INFINITY: &[u8] = &[0x00, 0x00, 0x00, 0x00, 0xd0, 0x00, 0x00, 0x00];
+
+#[test]
+fn matches_captured_fixtures() {
+    fn check<const SCALE: u32>(text: &str, bytes: &[u8]) {
+        let value: Decimal<i64, SCALE> = text.parse().unwrap();
+        assert_eq!(value.to_pg_numeric(), bytes, "{text}");
+        assert_eq!(Decimal::<i64, SCALE>::from_pg_numeric(bytes), Ok(value));
+    }
+
+    check::<4>(FIXTURES[0].0, FIXTURES[0].1);
+    check::<3>(FIXTURES[1].0, FIXTURES[1].1);
+    check::<1>(FIXTURES[2].0, FIXTURES[2].1);
+    check::<0>(FIXTURES[3].0, FIXTURES[3].1);
+    check::<2>(FIXTURES[4].0, FIXTURES[4].1);
+}
+
+#[test]
+fn decodes_into_other_scales() {
+    let (_, bytes) = FIXTURES[0];
+    let wide = Decimal::<i128, 8>::from_pg_numeric(bytes).unwrap();
+    assert_eq!(wide.to_string(), "1234.56780000");
+
+    assert_eq!(
+        Decimal::<i64, 2>::from_pg_numeric(bytes),
+        Err(DecimalError::TooManyFractionalDigits {
+            provided: 4,
+            allowed: 2
+        })
+    );
+    let rounded = Decimal::<i64, 2>::from_pg_numeric_rounded(bytes, RoundingMode::HalfUp).unwrap();
+    assert_eq!(rounded.to_string(), "1234.57");
+
+    // Trailing zeros within the scale do not count as lost precision.
+    let value: Decimal<i64, 6> = "1.5".parse().unwrap();
+    let exact = Decimal::<i64, 1>::from_pg_numeric(&value.to_pg_numeric()).unwrap();
+    assert_eq!(exact.to_string(), "1.5");
+
+    let (_, bytes) = FIXTURES[1];
+    let floor = Decimal::<i64, 2>::from_pg_numeric_rounded(bytes, RoundingMode::Floor).unwrap();
+    assert_eq!(floor.to_string(), "-0.01");
+    let ceiling = Decimal::<i64, 2>::from_pg_numeric_rounded(bytes, RoundingMode::Ceiling).unwrap();
+    assert_eq!(ceiling.to_string(), "0.00");
+}
+
+#[test]
+fn text_matches_binary() {
+    fn both<const SCALE: u32>(
+        text: &str,
+        bytes: &[u8],
+    ) -> Result<Decimal<i64, SCALE>, DecimalError> {
+        let binary = Decimal::<i64, SCALE>::from_pg_numeric(bytes);
+        assert_eq!(Decimal::from_pg_text(text), binary, "{text}");
+        binary
+    }
+
+    for &(text, bytes) in FIXTURES {
+        both::<0>(text, bytes).ok();
+        both::<2>(text, bytes).ok();
+        both::<4>(text, bytes).ok();
+    }
+
+    // SELECT numeric_send(1.5000)
+    let bytes = [
+        0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x01, 0x13, 0x88,
+    ];
+    assert_eq!(both::<2>("1.5000", &bytes).unwrap().to_string(), "1.50");
+    assert_eq!(
+        both::<0>("1.5000", &bytes),
+        Err(DecimalError::TooManyFractionalDigits {
+            provided: 1,
+            allowed: 0
+        })
+    );
+    assert_eq!(
+        both::<0>("10000", FIXTURES[3].1).unwrap().to_string(),
+        "10000"
+    );
+    assert_eq!(both::<2>("NaN", NAN), Err(DecimalError::NonFinite));
+    assert_eq!(
+        both::<2>("Infinity", INFINITY),
+        Err(DecimalError::NonFinite)
+    );
+    assert_eq!(
+        Decimal::<i64, 2>::from_pg_text("-Infinity"),
+        Err(DecimalError::NonFinite)
+    );
+}
+
+#[test]
+fn round_trips_extremes() {
+    for value in [Decimal::<i128, 18>::MIN, Decimal::<i128, 18>::MAX] {
+        let bytes = value.to_pg_numeric();
+        assert_eq!(Decimal::<i128, 18>::from_pg_numeric(&bytes), Ok(value));
+    }
+    for value in [Decimal::<i64, 7>::MIN, Decimal::<i64, 7>::MAX] {
+        let bytes = value.to_pg_numeric();
+        assert_eq!(Decimal::<i64, 7>::from_pg_numeric(&bytes), Ok(value));
+    }
+}
+
+#[test]
+fn rejects_special_and_malformed_values() {
+    assert_eq!(
+        Decimal::<i64, 2>::from_pg_numeric(NAN),
+        Err(DecimalError::NonFinite)
+    );
+    assert_eq!(
+        Decimal::<i64, 2>::from_pg_numeric_rounded(INFINITY, RoundingMode::HalfUp),
+        Err(DecimalError::NonFinite)
+    );
+
+    let (_, bytes) = FIXTURES[0];
+    assert_eq!(
+        Decimal::<i64, 4>::from_pg_numeric(&bytes[..10]),
+        Err(DecimalError::Truncated)
+    );
+    assert_eq!(
+        Decimal::<i64, 4>::from_pg_numeric(&bytes[..6]),
+        Err(DecimalError::Truncated)
+    );
+    let mut trailing = bytes.to_vec();
+    trailing.push(0);
+    assert_eq!(
+        Decimal::<i64, 4>::from_pg_numeric(&trailing),
+        Err(DecimalError::Invalid)
+    );
+    // A digit of 10000 is out of range for base 10000.
+    let bad_digit = [0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x27, 0x10];
+    assert_eq!(
+        Decimal::<i64, 0>::from_pg_numeric(&bad_digit),
+        Err(DecimalError::Invalid)
+    );
+
+    // 10^40 does not fit any backing type.
+    let huge = [0x00, 0x01, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01];
+    assert_eq!(
+        Decimal::<i128, 0>::from_pg_numeric(&huge),
+        Err(DecimalError::Overflow)
+    );
+}
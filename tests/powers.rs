@@ -0,0 +1,286 @@
+use decimal::{Decimal, DecimalError, RoundingMode};
+
+fn d2(input: &str) -> Decimal<i64, 2> {
+    input.parse().unwrap()
+}
+
+#[test]
+fn pow_exact_and_rounded() {
+    let value = d2("1.50");
+    assert_eq!(
+        value
+            .pow::<4>(2, RoundingMode::Truncate)
+            .unwrap()
+            .to_string(),
+        "2.2500"
+    );
+    // 2.25 rounded to one decimal is a tie.
+    assert_eq!(
+        value.pow::<1>(2, RoundingMode::HalfUp).unwrap().to_string(),
+        "2.3"
+    );
+    assert_eq!(
+        value
+            .pow::<1>(2, RoundingMode::HalfEven)
+            .unwrap()
+            .to_string(),
+        "2.2"
+    );
+    assert_eq!(
+        value
+            .pow::<2>(0, RoundingMode::HalfEven)
+            .unwrap()
+            .to_string(),
+        "1.00"
+    );
+    assert_eq!(
+        d2("-1.10")
+            .pow::<3>(3, RoundingMode::HalfUp)
+            .unwrap()
+            .to_string(),
+        "-1.331"
+    );
+}
+
+#[test]
+fn pow_compound_interest() {
+    // 1.005^360 = 6.022575212263...
+    let monthly = "1.005".parse::<Decimal<i64, 3>>().unwrap();
+    let factor = monthly.pow::<8>(360, RoundingMode::HalfEven).unwrap();
+    assert_eq!(factor.to_string(), "6.02257521");
+
+    let factor = monthly.pow::<2>(360, RoundingMode::Truncate).unwrap();
+    assert_eq!(factor.to_string(), "6.02");
+}
+
+#[test]
+fn pow_overflow() {
+    assert_eq!(
+        d2("2.00").pow::<2>(100, RoundingMode::HalfUp).unwrap_err(),
+        DecimalError::Overflow
+    );
+    assert_eq!(
+        d2("2.00")
+            .pow::<2>(u32::MAX, RoundingMode::HalfUp)
+            .unwrap_err(),
+        DecimalError::Overflow
+    );
+    assert_eq!(
+        d2("0.50").pow::<2>(u32::MAX, RoundingMode::HalfUp).unwrap(),
+        Decimal::zero()
+    );
+}
+
+#[test]
+fn powi_negative_exponents() {
+    assert_eq!(
+        d2("4.00")
+            .powi::<4>(-1, RoundingMode::HalfUp)
+            .unwrap()
+            .to_string(),
+        "0.2500"
+    );
+    assert_eq!(
+        d2("3.00")
+            .powi::<4>(-2, RoundingMode::HalfUp)
+            .unwrap()
+            .to_string(),
+        "0.1111"
+    );
+    assert_eq!(
+        d2("-2.00")
+            .powi::<3>(-3, RoundingMode::HalfEven)
+            .unwrap()
+            .to_string(),
+        "-0.125"
+    );
+    // 1.01^-12 = 0.887449...
+    assert_eq!(
+        d2("1.01")
+            .powi::<6>(-12, RoundingMode::HalfUp)
+            .unwrap()
+            .to_string(),
+        "0.887449"
+    );
+    assert_eq!(
+        d2("0.00").powi::<2>(-1, RoundingMode::HalfUp).unwrap_err(),
+        DecimalError::DivisionByZero
+    );
+    assert_eq!(
+        d2("0.01").powi::<2>(-20, RoundingMode::HalfUp).unwrap_err(),
+        DecimalError::Overflow
+    );
+    assert_eq!(
+        d2("2.00").powi::<2>(-200, RoundingMode::HalfUp),
+        Ok(Decimal::zero())
+    );
+    assert_eq!(
+        d2("2.00")
+            .powi::<2>(-200, RoundingMode::Ceiling)
+            .unwrap()
+            .to_string(),
+        "0.01"
+    );
+    assert_eq!(
+        d2("0.01")
+            .powi::<2>(-100_000, RoundingMode::HalfUp)
+            .unwrap_err(),
+        DecimalError::Overflow
+    );
+    assert_eq!(
+        d2("0.01")
+            .powi::<2>(i32::MIN, RoundingMode::HalfUp)
+            .unwrap_err(),
+        DecimalError::Overflow
+    );
+}
+
+#[test]
+fn sqrt_rounding() {
+    assert_eq!(
+        d2("2.25")
+            .sqrt::<2>(RoundingMode::HalfUp)
+            .unwrap()
+            .to_string(),
+        "1.50"
+    );
+    // sqrt(2) = 1.41421356237...
+    assert_eq!(
+        d2("2.00")
+            .sqrt::<10>(RoundingMode::HalfUp)
+            .unwrap()
+            .to_string(),
+        "1.4142135624"
+    );
+    assert_eq!(
+        d2("2.00")
+            .sqrt::<10>(RoundingMode::Truncate)
+            .unwrap()
+            .to_string(),
+        "1.4142135623"
+    );
+    let big = "170141183460469231731687303715884105727"
+        .parse::<Decimal<i128, 0>>()
+        .unwrap();
+    assert_eq!(
+        big.sqrt::<0>(RoundingMode::HalfUp).unwrap().to_string(),
+        "13043817825332782212"
+    );
+    assert_eq!(
+        d2("-1.00").sqrt::<2>(RoundingMode::HalfUp).unwrap_err(),
+        DecimalError::Domain
+    );
+}
+
+#[test]
+fn nth_root_rounding() {
+    let value = "-27".parse::<Decimal<i64, 0>>().unwrap();
+    assert_eq!(
+        value
+            .nth_root::<3>(3, RoundingMode::HalfUp)
+            .unwrap()
+            .to_string(),
+        "-3.000"
+    );
+    // 2^(1/12) = 1.0594630943592...
+    assert_eq!(
+        d2("2.00")
+            .nth_root::<12>(12, RoundingMode::HalfEven)
+            .unwrap()
+            .to_string(),
+        "1.059463094359"
+    );
+    assert_eq!(
+        d2("16.00")
+            .nth_root::<2>(4, RoundingMode::Truncate)
+            .unwrap()
+            .to_string(),
+        "2.00"
+    );
+    assert_eq!(
+        d2("16.00")
+            .nth_root::<2>(0, RoundingMode::Truncate)
+            .unwrap_err(),
+        DecimalError::Domain
+    );
+    assert_eq!(
+        d2("-16.00")
+            .nth_root::<2>(4, RoundingMode::Truncate)
+            .unwrap_err(),
+        DecimalError::Domain
+    );
+
+    // High degrees go through logarithms rather than a huge radicand.
+    // 2^(1/1000) = 1.000693387...
+    assert_eq!(
+        d2("2.00")
+            .nth_root::<6>(1000, RoundingMode::HalfUp)
+            .unwrap()
+            .to_string(),
+        "1.000693"
+    );
+    // 0.5^(1/100000) = 0.99999306855...
+    assert_eq!(
+        d2("0.50")
+            .nth_root::<8>(100_000, RoundingMode::HalfUp)
+            .unwrap()
+            .to_string(),
+        "0.99999307"
+    );
+    let odd = d2("-2.00")
+        .nth_root::<2>(u32::MAX, RoundingMode::Truncate)
+        .unwrap();
+    assert_eq!(odd.to_string(), "-1.00");
+    let above_one = d2("2.00").nth_root::<2>(u32::MAX - 1, RoundingMode::Ceiling);
+    assert_eq!(above_one.unwrap().to_string(), "1.01");
+    let below_one = d2("0.50").nth_root::<2>(u32::MAX - 1, RoundingMode::Floor);
+    assert_eq!(below_one.unwrap().to_string(), "0.99");
+    assert_eq!(
+        d2("1.00")
+            .nth_root::<2>(u32::MAX - 1, RoundingMode::Ceiling)
+            .unwrap()
+            .to_string(),
+        "1.00"
+    );
+    assert_eq!(
+        d2("0.00")
+            .nth_root::<2>(u32::MAX - 1, RoundingMode::Ceiling)
+            .unwrap(),
+        Decimal::zero()
+    );
+}
+
+#[test]
+fn directed_rounding() {
+    // -1.10^3 = -1.331
+    let cube = d2("-1.10");
+    assert_eq!(
+        cube.pow::<2>(3, RoundingMode::Floor).unwrap().to_string(),
+        "-1.34"
+    );
+    assert_eq!(
+        cube.pow::<2>(3, RoundingMode::Ceiling).unwrap().to_string(),
+        "-1.33"
+    );
+    assert_eq!(
+        d2("2.00")
+            .sqrt::<10>(RoundingMode::Floor)
+            .unwrap()
+            .to_string(),
+        "1.4142135623"
+    );
+    assert_eq!(
+        d2("2.00")
+            .sqrt::<10>(RoundingMode::Ceiling)
+            .unwrap()
+            .to_string(),
+        "1.4142135624"
+    );
+    assert_eq!(
+        d2("4.00")
+            .sqrt::<2>(RoundingMode::Ceiling)
+            .unwrap()
+            .to_string(),
+        "2.00"
+    );
+}
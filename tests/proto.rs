@@ -0,0 +1,134 @@
+#![cfg(feature = "prost")]
+
+use decimal::money::{Currency, Money};
+use decimal::proto;
+use decimal::{Decimal, DecimalError, RoundingMode};
+use prost::Message;
+
+fn message(value: &str) -> proto::Decimal {
+    proto::Decimal {
+        value: value.to_string(),
+    }
+}
+
+fn money(currency_code: &str, units: i64, nanos: i32) -> proto::Money {
+    proto::Money {
+        currency_code: currency_code.to_string(),
+        units,
+        nanos,
+    }
+}
+
+#[test]
+fn decimal_message() {
+    let value: Decimal<i64, 2> = "-1234.50".parse().unwrap();
+    assert_eq!(proto::Decimal::from(value).value, "-1234.50");
+
+    let parse = |text: &str| Decimal::<i64, 2>::try_from(&message(text));
+    assert_eq!(parse("-1234.5"), Ok(value));
+    assert_eq!(parse("+1.2345e3").unwrap().to_string(), "1234.50");
+    assert_eq!(parse("125E-2").unwrap().to_string(), "1.25");
+    assert_eq!(parse(".5").unwrap().to_string(), "0.50");
+    assert_eq!(parse("7.").unwrap().to_string(), "7.00");
+    assert_eq!(parse("1.50000").unwrap().to_string(), "1.50");
+    assert_eq!(parse("0.000e-40").unwrap().to_string(), "0.00");
+    assert_eq!(parse("").unwrap().to_string(), "0.00");
+
+    assert_eq!(
+        parse("1.255"),
+        Err(DecimalError::TooManyFractionalDigits {
+            provided: 3,
+            allowed: 2
+        })
+    );
+    assert_eq!(parse("1e30"), Err(DecimalError::Overflow));
+    assert_eq!(parse("NaN"), Err(DecimalError::NonFinite));
+    assert_eq!(parse("-Infinity"), Err(DecimalError::NonFinite));
+    for bad in [".", "1.2.3", "1e", "e5", "--1", "1,5", " 1"] {
+        assert_eq!(parse(bad), Err(DecimalError::Invalid), "{bad}");
+    }
+}
+
+#[test]
+fn money_round_trip() {
+    let amount = Money::<i64, 2>::new("-1234.56".parse().unwrap(), Currency::USD);
+    let message = proto::Money::try_from(amount).unwrap();
+    assert_eq!(message, money("USD", -1234, -560_000_000));
+    assert_eq!(Money::<i64, 2>::try_from(&message), Ok(amount));
+
+    // Wire round trip through prost.
+    let bytes = message.encode_to_vec();
+    assert_eq!(proto::Money::decode(bytes.as_slice()).unwrap(), message);
+
+    let yen = Money::<i64, 0>::try_from(&money("JPY", 1500, 0)).unwrap();
+    assert_eq!(yen.to_string(), "JPY 1500");
+}
+
+#[test]
+fn money_rounding() {
+    // Twelve decimals do not fit in nanos.
+    let precise = Money::<i128, 12>::new("0.000000001500".parse().unwrap(), Currency::EUR);
+    assert_eq!(
+        proto::Money::try_from(precise),
+        Err(DecimalError::TooManyFractionalDigits {
+            provided: 10,
+            allowed: 9
+        })
+    );
+    assert_eq!(
+        precise.to_proto(RoundingMode::HalfEven).unwrap(),
+        money("EUR", 0, 2)
+    );
+    assert_eq!(
+        precise.to_proto(RoundingMode::Truncate).unwrap(),
+        money("EUR", 0, 1)
+    );
+
+    let message = money("USD", 10, 125_000_000);
+    assert_eq!(
+        Money::<i64, 2>::try_from(&message),
+        Err(DecimalError::TooManyFractionalDigits {
+            provided: 3,
+            allowed: 2
+        })
+    );
+    let rounded = Money::<i64, 2>::from_proto(&message, RoundingMode::HalfUp).unwrap();
+    assert_eq!(rounded.to_string(), "USD 10.13");
+    let rounded = Money::<i64, 2>::from_proto(&message, RoundingMode::HalfEven).unwrap();
+    assert_eq!(rounded.to_string(), "USD 10.12");
+}
+
+#[test]
+fn money_validation() {
+    let check = |message: proto::Money| Money::<i64, 2>::from_proto(&message, RoundingMode::HalfUp);
+
+    assert_eq!(
+        check(money("USD", 1, -500_000_000)),
+        Err(DecimalError::Invalid)
+    );
+    assert_eq!(
+        check(money("USD", -1, 500_000_000)),
+        Err(DecimalError::Invalid)
+    );
+    assert_eq!(
+        check(money("USD", 0, 1_000_000_000)),
+        Err(DecimalError::Invalid)
+    );
+    assert_eq!(
+        check(money("USD", 0, -1_000_000_000)),
+        Err(DecimalError::Invalid)
+    );
+    assert_eq!(check(money("XYZ", 1, 0)), Err(DecimalError::Invalid));
+    assert_eq!(check(money("", 1, 0)), Err(DecimalError::Empty));
+
+    assert_eq!(
+        check(money("USD", 0, -500_000_000)).unwrap().to_string(),
+        "USD -0.50"
+    );
+
+    let huge = Money::<i128, 2>::new(Decimal::MAX, Currency::USD);
+    assert_eq!(
+        huge.to_proto(RoundingMode::HalfUp),
+        Err(DecimalError::Overflow)
+    );
+}
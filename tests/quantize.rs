@@ -0,0 +1,84 @@
+use decimal::instrument::InstrumentSpec;
+use decimal::{Decimal, DecimalError, RoundingMode};
+
+fn d2(input: &str) -> Decimal<i64, 2> {
+    input.parse().unwrap()
+}
+
+#[test]
+fn quantize_to_step() {
+    let tick = d2("0.05");
+    let quantize = |value: &str, mode| d2(value).quantize_to(tick, mode).unwrap().to_string();
+
+    assert_eq!(quantize("1.23", RoundingMode::HalfUp), "1.25");
+    assert_eq!(quantize("1.22", RoundingMode::HalfUp), "1.20");
+    assert_eq!(quantize("1.23", RoundingMode::Floor), "1.20");
+    assert_eq!(quantize("1.21", RoundingMode::Ceiling), "1.25");
+    assert_eq!(quantize("-1.23", RoundingMode::Floor), "-1.25");
+    assert_eq!(quantize("-1.23", RoundingMode::Ceiling), "-1.20");
+    assert_eq!(quantize("-1.23", RoundingMode::Truncate), "-1.20");
+    assert_eq!(quantize("1.25", RoundingMode::Floor), "1.25");
+
+    // Steps need not be powers of ten.
+    let quarter = d2("0.25");
+    assert_eq!(
+        d2("10.13")
+            .quantize_to(quarter, RoundingMode::HalfEven)
+            .unwrap()
+            .to_string(),
+        "10.25"
+    );
+
+    assert_eq!(
+        d2("1.00").quantize_to(d2("0"), RoundingMode::HalfUp),
+        Err(DecimalError::DivisionByZero)
+    );
+    assert_eq!(
+        d2("1.00").quantize_to(d2("-0.05"), RoundingMode::HalfUp),
+        Err(DecimalError::Invalid)
+    );
+}
+
+#[test]
+fn multiple_of_step() {
+    assert!(d2("1.25").is_multiple_of(d2("0.05")));
+    assert!(d2("-1.25").is_multiple_of(d2("0.25")));
+    assert!(!d2("1.26").is_multiple_of(d2("0.05")));
+    assert!(d2("0").is_multiple_of(d2("0.05")));
+    assert!(!d2("1.00").is_multiple_of(d2("0")));
+}
+
+#[test]
+fn instrument_spec() {
+    let spec: InstrumentSpec<i64, 2, 0> =
+        InstrumentSpec::new(d2("0.05"), "100".parse().unwrap()).unwrap();
+
+    assert_eq!(spec.validate(d2("10.15"), "300".parse().unwrap()), Ok(()));
+    assert_eq!(
+        spec.validate_price(d2("10.17")),
+        Err(DecimalError::InvalidIncrement)
+    );
+    assert_eq!(
+        spec.validate_quantity("150".parse().unwrap()),
+        Err(DecimalError::InvalidIncrement)
+    );
+    assert_eq!(
+        spec.validate_quantity("0".parse().unwrap()),
+        Err(DecimalError::Invalid)
+    );
+
+    // Bids round down and asks round up so neither crosses the limit.
+    assert_eq!(spec.round_bid(d2("10.17")).unwrap().to_string(), "10.15");
+    assert_eq!(spec.round_ask(d2("10.17")).unwrap().to_string(), "10.20");
+    assert_eq!(
+        spec.round_quantity("250".parse().unwrap())
+            .unwrap()
+            .to_string(),
+        "200"
+    );
+
+    assert_eq!(
+        InstrumentSpec::<i64, 2, 0>::new(d2("0"), "1".parse().unwrap()),
+        Err(DecimalError::Invalid)
+    );
+}
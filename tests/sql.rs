@@ -0,0 +1,88 @@
+#![cfg(any(feature = "postgres", feature = "sqlx", feature = "diesel"))]
+
+use decimal::Decimal;
+
+fn d2(input: &str) -> Decimal<i64, 2> {
+    input.parse().unwrap()
+}
+
+#[cfg(feature = "postgres")]
+#[test]
+fn postgres_types_round_trip() {
+    use bytes::BytesMut;
+    use decimal::DecimalError;
+    use postgres_types::{FromSql, IsNull, ToSql, Type};
+
+    let value = d2("-1234.50");
+    let mut buf = BytesMut::new();
+    assert!(matches!(
+        value.to_sql_checked(&Type::NUMERIC, &mut buf),
+        Ok(IsNull::No)
+    ));
+    assert_eq!(&buf[..], value.to_pg_numeric());
+    assert_eq!(
+        Decimal::<i64, 2>::from_sql(&Type::NUMERIC, &buf).unwrap(),
+        value
+    );
+
+    assert!(<Decimal<i64, 2> as FromSql>::accepts(&Type::NUMERIC));
+    assert!(!<Decimal<i64, 2> as ToSql>::accepts(&Type::FLOAT8));
+    assert!(value.to_sql_checked(&Type::TEXT, &mut buf).is_err());
+
+    // A NUMERIC(10,4) column value read into two decimals.
+    let wide: Decimal<i64, 4> = "1.2345".parse().unwrap();
+    let error = Decimal::<i64, 2>::from_sql(&Type::NUMERIC, &wide.to_pg_numeric()).unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<DecimalError>(),
+        Some(&DecimalError::TooManyFractionalDigits {
+            provided: 4,
+            allowed: 2
+        })
+    );
+}
+
+#[cfg(feature = "sqlx")]
+#[test]
+fn sqlx_encodes_numeric() {
+    use sqlx::encode::IsNull;
+    use sqlx::postgres::types::Oid;
+    use sqlx::postgres::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, Postgres};
+    use sqlx::{Encode, Type};
+
+    let info: PgTypeInfo = <Decimal<i64, 2> as Type<Postgres>>::type_info();
+    assert_eq!(info.oid(), Some(Oid(1700)));
+    assert!(<Decimal<i64, 2> as Type<Postgres>>::compatible(
+        &PgTypeInfo::with_oid(Oid(1700))
+    ));
+    assert!(!<Decimal<i64, 2> as Type<Postgres>>::compatible(
+        &PgTypeInfo::with_oid(Oid(20))
+    ));
+    assert_eq!(
+        <Decimal<i128, 6> as PgHasArrayType>::array_type_info().oid(),
+        Some(Oid(1231))
+    );
+
+    let value = d2("-1234.50");
+    let mut buf = PgArgumentBuffer::default();
+    let is_null = <Decimal<i64, 2> as Encode<Postgres>>::encode_by_ref(&value, &mut buf).unwrap();
+    assert!(matches!(is_null, IsNull::No));
+    assert_eq!(&buf[..], value.to_pg_numeric());
+}
+
+#[cfg(feature = "diesel")]
+#[test]
+fn diesel_binds_numeric() {
+    use diesel::deserialize::FromSql;
+    use diesel::dsl::select;
+    use diesel::pg::Pg;
+    use diesel::sql_types::Numeric;
+    use diesel::{IntoSql, debug_query};
+
+    fn maps_numeric<V: FromSql<Numeric, Pg> + diesel::serialize::ToSql<Numeric, Pg>>() {}
+    maps_numeric::<Decimal<i64, 2>>();
+    maps_numeric::<Decimal<i128, 18>>();
+
+    let query = select(d2("1.50").into_sql::<Numeric>());
+    let sql = debug_query::<Pg, _>(&query).to_string();
+    assert!(sql.starts_with("SELECT $1"), "{sql}");
+}
@@ -0,0 +1,166 @@
+use decimal::{Decimal, DecimalError, RoundingMode, stats};
+
+fn d2(input: &str) -> Decimal<i64, 2> {
+    input.parse().unwrap()
+}
+
+fn series(inputs: &[&str]) -> Vec<Decimal<i64, 2>> {
+    inputs.iter().map(|input| d2(input)).collect()
+}
+
+#[test]
+fn mean_rounding() {
+    let values = series(&["1.00", "2.00", "2.00"]);
+    let mean = stats::mean(values.iter().copied(), RoundingMode::HalfUp).unwrap();
+    assert_eq!(mean.to_string(), "1.67");
+
+    let mean = stats::mean(values, RoundingMode::Truncate).unwrap();
+    assert_eq!(mean.to_string(), "1.66");
+}
+
+#[test]
+fn weighted_mean_vwap() {
+    let trades = [("101.25", "300"), ("101.30", "200"), ("101.20", "500")].map(|(price, qty)| {
+        (
+            price.parse::<Decimal<i64, 4>>().unwrap(),
+            qty.parse::<Decimal<i64, 0>>().unwrap(),
+        )
+    });
+    // (30375 + 20260 + 50600) / 1000 = 101.235
+    let vwap = stats::weighted_mean(trades, RoundingMode::HalfEven).unwrap();
+    assert_eq!(vwap.to_string(), "101.2350");
+
+    let zero_weight = [(d2("1.00"), d2("1.00")), (d2("2.00"), d2("-1.00"))];
+    assert_eq!(
+        stats::weighted_mean(zero_weight, RoundingMode::HalfUp).unwrap_err(),
+        DecimalError::DivisionByZero
+    );
+}
+
+#[test]
+fn weighted_mean_beyond_i128_totals() {
+    // Each product is near 8.5e37, so the running total passes i128::MAX.
+    let price = Decimal::<i64, 8>::MAX;
+    let qty = Decimal::<i64, 8>::MAX;
+    let trades = [(price, qty); 4];
+    assert_eq!(
+        stats::weighted_mean(trades, RoundingMode::HalfEven).unwrap(),
+        price
+    );
+
+    // A negative total weight flips the sign, and Floor still rounds down.
+    let trades = [(d2("1.00"), d2("-2.00")), (d2("0.00"), d2("-1.00"))];
+    assert_eq!(
+        stats::weighted_mean(trades, RoundingMode::Floor)
+            .unwrap()
+            .to_string(),
+        "0.66"
+    );
+    let trades = [(d2("-1.00"), d2("2.00")), (d2("0.00"), d2("1.00"))];
+    assert_eq!(
+        stats::weighted_mean(trades, RoundingMode::Floor)
+            .unwrap()
+            .to_string(),
+        "-0.67"
+    );
+}
+
+#[test]
+fn median_odd_and_even() {
+    let values = series(&["3.00", "1.00", "2.00"]);
+    assert_eq!(
+        stats::median(&values, RoundingMode::HalfUp)
+            .unwrap()
+            .to_string(),
+        "2.00"
+    );
+
+    let values = series(&["0.01", "0.04", "-1.00", "5.00"]);
+    // (0.01 + 0.04) / 2 = 0.025
+    assert_eq!(
+        stats::median(&values, RoundingMode::HalfEven)
+            .unwrap()
+            .to_string(),
+        "0.02"
+    );
+    assert_eq!(
+        stats::median(&values, RoundingMode::HalfUp)
+            .unwrap()
+            .to_string(),
+        "0.03"
+    );
+}
+
+#[test]
+fn variance_and_std_dev() {
+    let values = series(&["2", "4", "4", "4", "5", "5", "7", "9"]);
+    let variance = stats::variance(values.iter().copied(), RoundingMode::HalfUp).unwrap();
+    assert_eq!(variance.to_string(), "4.00");
+    let std_dev = stats::std_dev(values.iter().copied(), RoundingMode::HalfUp).unwrap();
+    assert_eq!(std_dev.to_string(), "2.00");
+
+    // Sample variance = 32 / 7
+    let sample = stats::sample_variance(values.iter().copied(), RoundingMode::HalfUp).unwrap();
+    assert_eq!(sample.to_string(), "4.57");
+    // sqrt(32 / 7) = 2.13808...
+    let sample = stats::sample_std_dev(values.iter().copied(), RoundingMode::HalfUp).unwrap();
+    assert_eq!(sample.to_string(), "2.14");
+    let sample = stats::sample_std_dev(values, RoundingMode::Truncate).unwrap();
+    assert_eq!(sample.to_string(), "2.13");
+}
+
+#[test]
+fn std_dev_rounding() {
+    // Population variance of [0, 1] is 0.25, std dev 0.5.
+    let values = series(&["0", "1"]);
+    let value = stats::std_dev(values.iter().copied(), RoundingMode::HalfUp).unwrap();
+    assert_eq!(value.to_string(), "0.50");
+
+    let values = "0 1"
+        .split(' ')
+        .map(|s| s.parse::<Decimal<i64, 0>>().unwrap());
+    let value = stats::std_dev(values, RoundingMode::HalfEven).unwrap();
+    assert_eq!(value.to_string(), "0");
+}
+
+#[test]
+fn wide_variance_and_std_dev() {
+    // Squared minor units exceed i128.
+    let values = ["100000000000000000000", "100000000000000000002"]
+        .map(|s| s.parse::<Decimal<i128, 6>>().unwrap());
+    let variance = stats::variance(values, RoundingMode::HalfUp).unwrap();
+    assert_eq!(variance.to_string(), "1.000000");
+    let std_dev = stats::std_dev(values, RoundingMode::HalfUp).unwrap();
+    assert_eq!(std_dev.to_string(), "1.000000");
+    let sample = stats::sample_std_dev(values, RoundingMode::HalfEven).unwrap();
+    assert_eq!(sample.to_string(), "1.414214");
+
+    let extremes = [i128::MAX, -i128::MAX].map(Decimal::<i128, 6>::from_minor_units);
+    let std_dev = stats::std_dev(extremes, RoundingMode::HalfUp).unwrap();
+    assert_eq!(std_dev, extremes[0]);
+    assert_eq!(
+        stats::variance(extremes, RoundingMode::HalfUp).unwrap_err(),
+        DecimalError::Overflow
+    );
+}
+
+#[test]
+fn empty_and_degenerate_input() {
+    let empty: [Decimal<i64, 2>; 0] = [];
+    assert_eq!(
+        stats::mean(empty, RoundingMode::HalfUp).unwrap_err(),
+        DecimalError::Empty
+    );
+    assert_eq!(
+        stats::median(&empty, RoundingMode::HalfUp).unwrap_err(),
+        DecimalError::Empty
+    );
+    assert_eq!(
+        stats::variance(empty, RoundingMode::HalfUp).unwrap_err(),
+        DecimalError::Empty
+    );
+    assert_eq!(
+        stats::sample_variance([d2("1.00")], RoundingMode::HalfUp).unwrap_err(),
+        DecimalError::DivisionByZero
+    );
+}
@@ -0,0 +1,152 @@
+use decimal::tax::{self, TaxRounding, TaxTreatment};
+use decimal::{Decimal, DecimalError, RoundingMode};
+
+fn d2(input: &str) -> Decimal<i64, 2> {
+    input.parse().unwrap()
+}
+
+fn rate(input: &str) -> Decimal<i64, 4> {
+    input.parse().unwrap()
+}
+
+#[test]
+fn single_amounts() {
+    // 9.99 · 0.20 = 1.998
+    let tax = tax::tax_on_net(d2("9.99"), rate("0.20"), RoundingMode::HalfUp).unwrap();
+    assert_eq!(tax.to_string(), "2.00");
+
+    // 9.99 · 0.20 / 1.20 = 1.665
+    let tax = tax::tax_in_gross(d2("9.99"), rate("0.20"), RoundingMode::HalfUp).unwrap();
+    assert_eq!(tax.to_string(), "1.67");
+    let tax = tax::tax_in_gross(d2("9.99"), rate("0.20"), RoundingMode::HalfEven).unwrap();
+    assert_eq!(tax.to_string(), "1.66");
+    let tax = tax::tax_in_gross(d2("1.20"), rate("0.20"), RoundingMode::HalfUp).unwrap();
+    assert_eq!(tax.to_string(), "0.20");
+
+    assert_eq!(
+        tax::tax_on_net(d2("1.00"), rate("-0.10"), RoundingMode::HalfUp),
+        Err(DecimalError::Invalid)
+    );
+}
+
+#[test]
+fn line_versus_document_rounding() {
+    let lines = [
+        (d2("1.99"), rate("0.20")),
+        (d2("10.00"), rate("0.05")),
+        (d2("1.99"), rate("0.20")),
+        (d2("1.99"), rate("0.20")),
+    ];
+
+    // Each line: 1.99 · 0.20 = 0.398 → 0.40.
+    let by_line = tax::breakdown(
+        &lines,
+        TaxTreatment::Exclusive,
+        TaxRounding::Line,
+        RoundingMode::HalfUp,
+    )
+    .unwrap();
+    let taxes: Vec<String> = by_line
+        .lines
+        .iter()
+        .map(|line| line.tax.to_string())
+        .collect();
+    assert_eq!(taxes, ["0.40", "0.50", "0.40", "0.40"]);
+    assert_eq!(by_line.tax.to_string(), "1.70");
+    assert_eq!(by_line.gross.to_string(), "17.67");
+
+    // Per rate: 5.97 · 0.20 = 1.194 → 1.19, shared out as 0.40 + 0.40 + 0.39.
+    let by_document = tax::breakdown(
+        &lines,
+        TaxTreatment::Exclusive,
+        TaxRounding::Document,
+        RoundingMode::HalfUp,
+    )
+    .unwrap();
+    let taxes: Vec<String> = by_document
+        .lines
+        .iter()
+        .map(|line| line.tax.to_string())
+        .collect();
+    assert_eq!(taxes, ["0.40", "0.50", "0.40", "0.39"]);
+    assert_eq!(by_document.rates.len(), 2);
+    assert_eq!(by_document.rates[0].rate, rate("0.20"));
+    assert_eq!(by_document.rates[0].net.to_string(), "5.97");
+    assert_eq!(by_document.rates[0].tax.to_string(), "1.19");
+    assert_eq!(by_document.rates[1].tax.to_string(), "0.50");
+    assert_eq!(by_document.tax.to_string(), "1.69");
+    assert_eq!(by_document.net.to_string(), "15.97");
+    assert_eq!(by_document.gross.to_string(), "17.66");
+}
+
+#[test]
+fn inclusive_breakdown_reconciles() {
+    let lines = [
+        (d2("9.99"), rate("0.20")),
+        (d2("4.99"), rate("0.07")),
+        (d2("9.99"), rate("0.20")),
+        (d2("-2.50"), rate("0.20")),
+    ];
+
+    for rounding in [TaxRounding::Line, TaxRounding::Document] {
+        let breakdown = tax::breakdown(
+            &lines,
+            TaxTreatment::Inclusive,
+            rounding,
+            RoundingMode::HalfEven,
+        )
+        .unwrap();
+
+        for (line, (amount, _)) in breakdown.lines.iter().zip(&lines) {
+            assert_eq!(line.gross, *amount);
+            assert_eq!(line.net + line.tax, line.gross);
+        }
+        for total in &breakdown.rates {
+            let lines = breakdown
+                .lines
+                .iter()
+                .filter(|line| line.rate == total.rate);
+            assert_eq!(
+                lines.map(|line| line.tax).sum::<Decimal<i64, 2>>(),
+                total.tax
+            );
+        }
+        let rate_tax: Decimal<i64, 2> = breakdown.rates.iter().map(|total| total.tax).sum();
+        assert_eq!(rate_tax, breakdown.tax);
+        assert_eq!(breakdown.net + breakdown.tax, breakdown.gross);
+        assert_eq!(breakdown.gross.to_string(), "22.47");
+    }
+
+    // 17.48 · 0.20 / 1.20 = 2.91333... at document level.
+    let breakdown = tax::breakdown(
+        &lines,
+        TaxTreatment::Inclusive,
+        TaxRounding::Document,
+        RoundingMode::HalfEven,
+    )
+    .unwrap();
+    assert_eq!(breakdown.rates[0].tax.to_string(), "2.91");
+}
+
+#[test]
+fn breakdown_errors() {
+    let lines: [(Decimal<i64, 2>, Decimal<i64, 4>); 0] = [];
+    assert_eq!(
+        tax::breakdown(
+            &lines,
+            TaxTreatment::Exclusive,
+            TaxRounding::Line,
+            RoundingMode::HalfUp
+        ),
+        Err(DecimalError::Empty)
+    );
+    assert_eq!(
+        tax::breakdown(
+            &[(d2("1.00"), rate("-0.20"))],
+            TaxTreatment::Inclusive,
+            TaxRounding::Document,
+            RoundingMode::HalfUp
+        ),
+        Err(DecimalError::Invalid)
+    );
+}
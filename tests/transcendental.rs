@@ -0,0 +1,262 @@
+use decimal::{Decimal, DecimalError, RoundingMode};
+
+fn d(input: &str) -> Decimal<i64, 4> {
+    input.parse().unwrap()
+}
+
+#[test]
+fn exp_values() {
+    let one = d("1");
+    assert_eq!(
+        one.exp::<18>(RoundingMode::HalfUp).unwrap().to_string(),
+        "2.718281828459045235"
+    );
+    assert_eq!(
+        d("-1").exp::<10>(RoundingMode::HalfUp).unwrap().to_string(),
+        "0.3678794412"
+    );
+    assert_eq!(
+        d("10")
+            .exp::<6>(RoundingMode::Truncate)
+            .unwrap()
+            .to_string(),
+        "22026.465794"
+    );
+    assert_eq!(
+        d("0").exp::<4>(RoundingMode::Truncate).unwrap().to_string(),
+        "1.0000"
+    );
+    let wide = "43".parse::<Decimal<i128, 0>>().unwrap();
+    assert_eq!(
+        wide.exp::<2>(RoundingMode::HalfEven).unwrap().to_string(),
+        "4727839468229346561.47"
+    );
+}
+
+#[test]
+fn exp_overflow_and_underflow() {
+    assert_eq!(
+        d("100").exp::<2>(RoundingMode::HalfUp).unwrap_err(),
+        DecimalError::Overflow
+    );
+    assert_eq!(
+        d("-200").exp::<18>(RoundingMode::HalfUp).unwrap(),
+        Decimal::zero()
+    );
+    let huge = Decimal::<i64, 0>::MAX;
+    assert_eq!(
+        huge.exp::<2>(RoundingMode::HalfUp).unwrap_err(),
+        DecimalError::Overflow
+    );
+}
+
+#[test]
+fn ln_values() {
+    assert_eq!(
+        d("2").ln::<18>(RoundingMode::HalfUp).unwrap().to_string(),
+        "0.693147180559945309"
+    );
+    assert_eq!(
+        d("0.5").ln::<18>(RoundingMode::HalfUp).unwrap().to_string(),
+        "-0.693147180559945309"
+    );
+    assert_eq!(
+        d("10").ln::<10>(RoundingMode::HalfUp).unwrap().to_string(),
+        "2.3025850930"
+    );
+    assert_eq!(
+        d("0.0001")
+            .ln::<6>(RoundingMode::Truncate)
+            .unwrap()
+            .to_string(),
+        "-9.210340"
+    );
+    assert_eq!(
+        d("1").ln::<4>(RoundingMode::Truncate).unwrap().to_string(),
+        "0.0000"
+    );
+}
+
+#[test]
+fn log10_values() {
+    assert_eq!(
+        d("1000")
+            .log10::<4>(RoundingMode::Truncate)
+            .unwrap()
+            .to_string(),
+        "3.0000"
+    );
+    assert_eq!(
+        d("0.001")
+            .log10::<2>(RoundingMode::HalfUp)
+            .unwrap()
+            .to_string(),
+        "-3.00"
+    );
+    assert_eq!(
+        d("2")
+            .log10::<10>(RoundingMode::HalfUp)
+            .unwrap()
+            .to_string(),
+        "0.3010299957"
+    );
+}
+
+#[test]
+fn log_domain_errors() {
+    assert_eq!(
+        d("0").ln::<4>(RoundingMode::HalfUp).unwrap_err(),
+        DecimalError::Domain
+    );
+    assert_eq!(
+        d("-1").ln::<4>(RoundingMode::HalfUp).unwrap_err(),
+        DecimalError::Domain
+    );
+    assert_eq!(
+        d("-1").log10::<4>(RoundingMode::HalfUp).unwrap_err(),
+        DecimalError::Domain
+    );
+}
+
+#[test]
+fn powf_values() {
+    let half = "0.5".parse::<Decimal<i64, 1>>().unwrap();
+    assert_eq!(
+        d("2")
+            .powf::<1, 12>(half, RoundingMode::HalfUp)
+            .unwrap()
+            .to_string(),
+        "1.414213562373"
+    );
+    // Exact result under Truncate sits on a rounding boundary.
+    assert_eq!(
+        d("4")
+            .powf::<1, 6>(half, RoundingMode::Truncate)
+            .unwrap()
+            .to_string(),
+        "2.000000"
+    );
+    assert_eq!(
+        d("4")
+            .powf::<1, 6>(half, RoundingMode::Ceiling)
+            .unwrap()
+            .to_string(),
+        "2.000000"
+    );
+    let exponent = "-0.25".parse::<Decimal<i64, 2>>().unwrap();
+    assert_eq!(
+        d("0.0625")
+            .powf::<2, 3>(exponent, RoundingMode::Floor)
+            .unwrap()
+            .to_string(),
+        "2.000"
+    );
+    let exponent = "2.5".parse::<Decimal<i64, 1>>().unwrap();
+    assert_eq!(
+        d("1.05")
+            .powf::<1, 8>(exponent, RoundingMode::HalfUp)
+            .unwrap()
+            .to_string(),
+        "1.12972632"
+    );
+    let exponent = "-1.5".parse::<Decimal<i64, 1>>().unwrap();
+    assert_eq!(
+        d("0.5")
+            .powf::<1, 10>(exponent, RoundingMode::HalfEven)
+            .unwrap()
+            .to_string(),
+        "2.8284271247"
+    );
+}
+
+#[test]
+fn powf_special_cases() {
+    let three = "3.0".parse::<Decimal<i64, 1>>().unwrap();
+    assert_eq!(
+        d("-2")
+            .powf::<1, 2>(three, RoundingMode::HalfUp)
+            .unwrap()
+            .to_string(),
+        "-8.00"
+    );
+    let half = "0.5".parse::<Decimal<i64, 1>>().unwrap();
+    assert_eq!(
+        d("-2")
+            .powf::<1, 2>(half, RoundingMode::HalfUp)
+            .unwrap_err(),
+        DecimalError::Domain
+    );
+    assert_eq!(
+        d("0")
+            .powf::<1, 2>(Decimal::zero() - half, RoundingMode::HalfUp)
+            .unwrap_err(),
+        DecimalError::DivisionByZero
+    );
+    assert!(
+        d("0")
+            .powf::<1, 2>(half, RoundingMode::HalfUp)
+            .unwrap()
+            .is_zero()
+    );
+}
+
+#[test]
+fn exact_results_round_directly() {
+    // 0.25^1.5 = 0.125 and 2.25^0.5 = 1.5 sit exactly on half-way points.
+    let three_halves = "1.5".parse::<Decimal<i64, 1>>().unwrap();
+    let quarter = d("0.25");
+    assert_eq!(
+        quarter
+            .powf::<1, 2>(three_halves, RoundingMode::HalfEven)
+            .unwrap()
+            .to_string(),
+        "0.12"
+    );
+    assert_eq!(
+        quarter
+            .powf::<1, 2>(three_halves, RoundingMode::HalfUp)
+            .unwrap()
+            .to_string(),
+        "0.13"
+    );
+    let half = "0.5".parse::<Decimal<i64, 1>>().unwrap();
+    assert_eq!(
+        d("2.25")
+            .powf::<1, 0>(half, RoundingMode::HalfEven)
+            .unwrap()
+            .to_string(),
+        "2"
+    );
+    assert_eq!(
+        d("4")
+            .powf::<1, 0>(Decimal::zero() - half, RoundingMode::HalfEven)
+            .unwrap()
+            .to_string(),
+        "0"
+    );
+    let quarter_power = "0.25".parse::<Decimal<i64, 2>>().unwrap();
+    assert_eq!(
+        d("16")
+            .powf::<2, 4>(quarter_power, RoundingMode::Floor)
+            .unwrap()
+            .to_string(),
+        "2.0000"
+    );
+    let long = "12345.6789".parse::<Decimal<i64, 4>>().unwrap();
+    assert_eq!(
+        d("1")
+            .powf::<4, 4>(long, RoundingMode::Ceiling)
+            .unwrap()
+            .to_string(),
+        "1.0000"
+    );
+
+    assert_eq!(
+        d("0").exp::<4>(RoundingMode::Ceiling).unwrap().to_string(),
+        "1.0000"
+    );
+    assert_eq!(
+        d("1").ln::<4>(RoundingMode::Ceiling).unwrap().to_string(),
+        "0.0000"
+    );
+}
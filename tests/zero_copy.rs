@@ -0,0 +1,71 @@
+use std::mem::{align_of, size_of};
+
+use decimal::Decimal;
+
+fn d2(input: &str) -> Decimal<i64, 2> {
+    input.parse().unwrap()
+}
+
+#[test]
+fn layout_matches_backing() {
+    assert_eq!(size_of::<Decimal<i64, 2>>(), size_of::<i64>());
+    assert_eq!(align_of::<Decimal<i64, 2>>(), align_of::<i64>());
+    assert_eq!(size_of::<Decimal<i128, 6>>(), size_of::<i128>());
+    assert_eq!(align_of::<Decimal<i128, 6>>(), align_of::<i128>());
+
+    let minor = [150_i64, -25];
+    // SAFETY: `Decimal` is `repr(transparent)` over its backing integer.
+    let values: &[Decimal<i64, 2>] =
+        unsafe { std::slice::from_raw_parts(minor.as_ptr().cast(), minor.len()) };
+    assert_eq!(values, [d2("1.50"), d2("-0.25")]);
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn bytemuck_casts() {
+    use bytemuck::TransparentWrapper;
+
+    let minor: Vec<i64> = vec![150, -25, 0];
+    let values: &[Decimal<i64, 2>] = bytemuck::cast_slice(&minor);
+    assert_eq!(values, [d2("1.50"), d2("-0.25"), d2("0")]);
+    assert_eq!(Decimal::<i64, 2>::wrap_slice(&minor), values);
+
+    let bytes: &[u8] = bytemuck::cast_slice(values);
+    assert_eq!(bytes.len(), 24);
+    assert_eq!(&bytes[..8], &150_i64.to_ne_bytes());
+    let back: &[Decimal<i64, 2>] = bytemuck::cast_slice(bytes);
+    assert_eq!(back, values);
+
+    assert_eq!(<Decimal<i64, 2> as bytemuck::Zeroable>::zeroed(), d2("0"));
+    let wide: Decimal<i128, 6> = bytemuck::cast(-1_500_000_i128);
+    assert_eq!(wide.to_string(), "-1.500000");
+}
+
+#[cfg(feature = "rkyv")]
+#[test]
+fn rkyv_round_trip() {
+    use decimal::ArchivedDecimal;
+    use rkyv::rancor::Error;
+    use rkyv::vec::ArchivedVec;
+
+    let values = vec![d2("1.50"), d2("-0.25"), d2("92233720368547758.07")];
+    let bytes = rkyv::to_bytes::<Error>(&values).unwrap();
+
+    let archived = rkyv::access::<ArchivedVec<ArchivedDecimal<i64, 2>>, Error>(&bytes).unwrap();
+    assert_eq!(archived.len(), 3);
+    assert_eq!(archived[1].to_native(), d2("-0.25"));
+    assert!(
+        archived
+            .iter()
+            .zip(&values)
+            .all(|(archived, value)| archived == value)
+    );
+
+    let back = rkyv::deserialize::<Vec<Decimal<i64, 2>>, Error>(archived).unwrap();
+    assert_eq!(back, values);
+
+    let wide: Decimal<i128, 6> = "-12345678901234567890.123456".parse().unwrap();
+    let bytes = rkyv::to_bytes::<Error>(&wide).unwrap();
+    let archived = rkyv::access::<ArchivedDecimal<i128, 6>, Error>(&bytes).unwrap();
+    assert_eq!(archived.to_native(), wide);
+}